@@ -0,0 +1,68 @@
+//! Support of blocking clients, generated with `reqwest-blocking` feature.
+//!
+//! Blocking clients send requests with [`reqwest::blocking::Client`] and shouldn't be used
+//! inside of async runtime.
+
+use crate::client_error::ClientError;
+use crate::request_options::RequestOptions;
+use crate::transport::{with_url, Timeout};
+
+/// State of generated blocking client, shared by endpoints of all contracts, that client
+/// implements.
+///
+/// Endpoints of each contract are provided by generated `[contract_name]BlockingApi` trait.
+pub trait BlockingContractClient {
+    /// Root url of service.
+    fn root(&self) -> &str;
+
+    /// HTTP client, that sends requests of all endpoints.
+    fn http_client(&self) -> &reqwest::blocking::Client;
+}
+
+/// Send encoded request with options.
+#[doc(hidden)]
+pub fn execute(
+    http_client: &reqwest::blocking::Client,
+    options: &RequestOptions,
+    mut request: http::Request<Vec<u8>>,
+) -> Result<http::Response<Vec<u8>>, ClientError> {
+    options.apply(&mut request);
+
+    let response = http_client.execute(into_reqwest(request)?)?;
+
+    let mut head = http::Response::new(());
+    *head.status_mut() = response.status();
+    *head.version_mut() = response.version();
+    *head.headers_mut() = response.headers().clone();
+    let url = response.url().clone();
+
+    let body = response.bytes()?;
+
+    Ok(with_url(head, &url).map(|()| body.to_vec()))
+}
+
+/// Unsent request of blocking HTTP client from encoded request.
+#[doc(hidden)]
+pub fn prepare(
+    http_client: &reqwest::blocking::Client,
+    request: http::Request<Vec<u8>>,
+) -> Result<reqwest::blocking::RequestBuilder, ClientError> {
+    Ok(reqwest::blocking::RequestBuilder::from_parts(
+        http_client.clone(),
+        into_reqwest(request)?,
+    ))
+}
+
+fn into_reqwest(
+    request: http::Request<Vec<u8>>,
+) -> Result<reqwest::blocking::Request, ClientError> {
+    let timeout = request.extensions().get::<Timeout>().copied();
+    let mut request = reqwest::blocking::Request::try_from(request)
+        .map_err(|err| ClientError::Encode(Box::new(err)))?;
+
+    if let Some(Timeout(timeout)) = timeout {
+        *request.timeout_mut() = Some(timeout);
+    }
+
+    Ok(request)
+}
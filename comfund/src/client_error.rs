@@ -0,0 +1,142 @@
+//! Errors of generated clients.
+
+use crate::error::Problem;
+
+/// Maximum length (in bytes) of response body, kept by [`ClientError::Status`].
+pub const BODY_SNIPPET_LIMIT: usize = 1024;
+
+/// Boxed error of transport or decoding.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Error of request, sent by generated client.
+#[derive(Debug)]
+pub enum ClientError {
+    /// Url of endpoint couldn't be built from path params.
+    Url(paths::path_serializer::Error),
+    /// Request couldn't be encoded from arguments of endpoint (i.e. query or body couldn't be
+    /// serialized, or header value is invalid). Request wasn't sent.
+    Encode(BoxError),
+    /// Request couldn't be built or sent, or response couldn't be received.
+    ///
+    /// Errors of `ReqwestTransport` are `reqwest::Error`s.
+    Transport(BoxError),
+    /// Response status wasn't the one, declared for endpoint (or a 2xx code, if not declared),
+    /// and its body couldn't be decoded as endpoint error.
+    Status {
+        status: http::StatusCode,
+        headers: http::HeaderMap,
+        /// Beginning of response body, at most [`BODY_SNIPPET_LIMIT`] bytes long.
+        body: String,
+    },
+    /// Body of successful response couldn't be decoded.
+    Decode(BoxError),
+    /// Unsuccessful response with `application/problem+json` body, that couldn't be
+    /// decoded as endpoint error.
+    Problem(Box<Problem>),
+}
+
+impl ClientError {
+    /// Status code of response, if error was caused by unsuccessful one.
+    pub fn status(&self) -> Option<http::StatusCode> {
+        match self {
+            Self::Status { status, .. } => Some(*status),
+            Self::Problem(problem) => http::StatusCode::from_u16(problem.status).ok(),
+            #[cfg(feature = "reqwest")]
+            Self::Transport(err) | Self::Decode(err) => err
+                .downcast_ref::<reqwest::Error>()
+                .and_then(reqwest::Error::status),
+            #[cfg(not(feature = "reqwest"))]
+            Self::Transport(_) | Self::Decode(_) => None,
+            Self::Url(_) | Self::Encode(_) => None,
+        }
+    }
+
+    /// Decode error of endpoint from body of unsuccessful response.
+    ///
+    /// Problem details are returned as [`ClientError::Problem`], if body can't be
+    /// decoded as `E`.
+    #[doc(hidden)]
+    pub fn decode<E: serde::de::DeserializeOwned>(
+        parts: &http::response::Parts,
+        body: &[u8],
+    ) -> Result<E, Self> {
+        if let Ok(err) = serde_json::from_slice(body) {
+            return Ok(err);
+        }
+
+        Err(Self::from_body(parts.status, parts.headers.clone(), body))
+    }
+
+    /// Error for unsuccessful response of endpoint without typed errors.
+    #[doc(hidden)]
+    pub fn unexpected(parts: &http::response::Parts, body: &[u8]) -> Self {
+        Self::from_body(parts.status, parts.headers.clone(), body)
+    }
+
+    fn from_body(status: http::StatusCode, headers: http::HeaderMap, body: &[u8]) -> Self {
+        let is_problem = headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/problem+json"));
+
+        if is_problem {
+            if let Ok(problem) = serde_json::from_slice(body) {
+                return Self::Problem(Box::new(problem));
+            }
+        }
+
+        let snippet = &body[..body.len().min(BODY_SNIPPET_LIMIT)];
+
+        Self::Status {
+            status,
+            headers,
+            body: String::from_utf8_lossy(snippet).into_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(err) => write!(f, "failed to build endpoint url: {err}"),
+            Self::Encode(err) => write!(f, "failed to encode request: {err}"),
+            Self::Transport(err) => write!(f, "failed to send request: {err}"),
+            Self::Status { status, body, .. } if body.is_empty() => {
+                write!(f, "unexpected response status {status}")
+            }
+            Self::Status { status, body, .. } => {
+                write!(f, "unexpected response status {status}: {body}")
+            }
+            Self::Decode(err) => write!(f, "failed to decode response body: {err}"),
+            Self::Problem(problem) => write!(f, "service responded with problem: {problem}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Url(err) => Some(err),
+            Self::Encode(err) | Self::Transport(err) | Self::Decode(err) => Some(err.as_ref()),
+            Self::Status { .. } => None,
+            Self::Problem(problem) => Some(problem.as_ref()),
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for ClientError {
+    fn from(value: reqwest::Error) -> Self {
+        if value.is_decode() {
+            Self::Decode(Box::new(value))
+        } else {
+            Self::Transport(Box::new(value))
+        }
+    }
+}
+
+impl From<paths::path_serializer::Error> for ClientError {
+    fn from(value: paths::path_serializer::Error) -> Self {
+        Self::Url(value)
+    }
+}
@@ -0,0 +1,108 @@
+//! Encoding of requests and decoding of responses, shared by generated clients.
+
+use http::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+
+use crate::client_error::ClientError;
+use crate::multipart::Form;
+
+/// Builder of encoded request of endpoint.
+#[doc(hidden)]
+pub struct RequestEncoder {
+    method: http::Method,
+    url: String,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl RequestEncoder {
+    pub fn new(method: http::Method, url: String) -> Self {
+        Self {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Append serialized query params to url.
+    pub fn query<Q: serde::Serialize + ?Sized>(mut self, query: &Q) -> Result<Self, ClientError> {
+        let query = serde_urlencoded::to_string(query).map_err(encode_error)?;
+
+        if !query.is_empty() {
+            self.url
+                .push(if self.url.contains('?') { '&' } else { '?' });
+            self.url.push_str(&query);
+        }
+
+        Ok(self)
+    }
+
+    pub fn header(mut self, name: &str, value: String) -> Result<Self, ClientError> {
+        let name = HeaderName::try_from(name).map_err(encode_error)?;
+        let value = HeaderValue::try_from(value).map_err(encode_error)?;
+
+        self.headers.append(name, value);
+        Ok(self)
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn json<B: serde::Serialize + ?Sized>(self, body: &B) -> Result<Self, ClientError> {
+        let body = serde_json::to_vec(body).map_err(encode_error)?;
+
+        Ok(self.typed_body("application/json", body))
+    }
+
+    pub fn form<B: serde::Serialize + ?Sized>(self, body: &B) -> Result<Self, ClientError> {
+        let body = serde_urlencoded::to_string(body).map_err(encode_error)?;
+
+        Ok(self.typed_body("application/x-www-form-urlencoded", body.into_bytes()))
+    }
+
+    pub fn multipart(self, form: Form) -> Result<Self, ClientError> {
+        let (content_type, body) = form.encode();
+
+        self.header(CONTENT_TYPE.as_str(), content_type)
+            .map(|encoder| encoder.body(body))
+    }
+
+    pub fn build(self) -> Result<http::Request<Vec<u8>>, ClientError> {
+        let mut request = http::Request::builder()
+            .method(self.method)
+            .uri(self.url)
+            .body(self.body)
+            .map_err(encode_error)?;
+
+        *request.headers_mut() = self.headers;
+
+        Ok(request)
+    }
+
+    fn typed_body(mut self, content_type: &'static str, body: Vec<u8>) -> Self {
+        if !self.headers.contains_key(CONTENT_TYPE) {
+            self.headers
+                .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+        }
+
+        self.body(body)
+    }
+}
+
+/// Decode JSON body of successful response.
+#[doc(hidden)]
+pub fn decode_json<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, ClientError> {
+    serde_json::from_slice(body).map_err(|err| ClientError::Decode(Box::new(err)))
+}
+
+/// Decode text body of successful response. Invalid UTF-8 sequences are replaced.
+#[doc(hidden)]
+pub fn decode_text(body: &[u8]) -> Result<String, ClientError> {
+    Ok(String::from_utf8_lossy(body).into_owned())
+}
+
+fn encode_error(err: impl Into<crate::client_error::BoxError>) -> ClientError {
+    ClientError::Encode(err.into())
+}
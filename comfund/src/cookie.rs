@@ -0,0 +1,64 @@
+//! Encoding of cookie params, shared by generated clients and servers.
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// Characters, that are not allowed in cookie values (see `cookie-octet` in RFC 6265),
+/// plus `%` itself to keep encoding reversible.
+const COOKIE_VALUE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b',')
+    .add(b';')
+    .add(b'\\')
+    .add(b'%');
+
+/// Render value of `Cookie` header from pairs of cookie names and values.
+///
+/// Values are percent encoded, names are expected to be valid cookie names.
+pub fn encode<'n, I>(cookies: I) -> String
+where
+    I: IntoIterator<Item = (&'n str, String)>,
+{
+    cookies
+        .into_iter()
+        .map(|(name, value)| format!("{name}={}", utf8_percent_encode(&value, COOKIE_VALUE)))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Find value of cookie with given name in value of `Cookie` header.
+///
+/// ## Returns
+///
+/// Percent decoded value of first cookie with matching name or `None`,
+/// if there is no such cookie or its value is not a valid UTF-8 string.
+pub fn find(header: &str, name: &str) -> Option<String> {
+    header
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .and_then(|(_, value)| {
+            percent_decode_str(value.trim_matches('"'))
+                .decode_utf8()
+                .ok()
+                .map(|value| value.into_owned())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode, find};
+
+    #[test]
+    fn test_round_trip() {
+        let header = encode([
+            ("session", "a b;c".to_owned()),
+            ("locale", "uk-UA".to_owned()),
+        ]);
+
+        assert_eq!(header, "session=a%20b%3Bc; locale=uk-UA");
+        assert_eq!(find(&header, "session").as_deref(), Some("a b;c"));
+        assert_eq!(find(&header, "locale").as_deref(), Some("uk-UA"));
+        assert_eq!(find(&header, "missing"), None);
+    }
+}
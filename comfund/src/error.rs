@@ -0,0 +1,220 @@
+//! Typed errors of endpoints.
+//!
+//! Endpoints, that return `Result<T, E>`, send their errors as serialized bodies of responses
+//! with error status codes. Error types should implement [`EndpointError`] (as well as
+//! `serde` traits) to be used by generated code.
+//!
+//! ```
+//! use comfund::error::EndpointError;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize, EndpointError)]
+//! pub enum DivisionError {
+//!     #[status(400)]
+//!     DivisionByZero,
+//!     // Sent with `500 Internal Server Error`
+//!     Overflow,
+//! }
+//! ```
+//!
+//! On server side, errors are returned from handlers wrapped into [`ErrorResponse`].
+//! Clients decode errors from bodies of unsuccessful responses and return them as `Ok(Err(error))`.
+//!
+//! [`Problem`] is a ready-made error type with RFC 7807 wire format, that can be used
+//! for any endpoint or as a contract-wide default with `#[contract(error = comfund::Problem)]`.
+
+use serde::{Deserialize, Serialize};
+
+pub use comfund_macros::EndpointError;
+
+/// Error side of `Result`, returned by endpoints.
+///
+/// Can be derived with status codes, declared by `#[status(code)]` attributes.
+pub trait EndpointError {
+    /// `content-type` of response body with serialized error.
+    const CONTENT_TYPE: &'static str = "application/json";
+
+    /// Status code of response with error. Should be a client or server error code (4xx or 5xx).
+    fn status(&self) -> u16 {
+        500
+    }
+}
+
+/// RFC 7807 problem details, sent as `application/problem+json` body.
+///
+/// Members, not defined by RFC, are kept in `extensions`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Problem {
+    /// URI reference, that identifies problem type.
+    #[serde(rename = "type", default = "Problem::default_type")]
+    pub r#type: String,
+    /// Short, human-readable summary of problem type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Status code of response.
+    #[serde(default = "Problem::default_status")]
+    pub status: u16,
+    /// Human-readable explanation, specific to this occurrence of problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// URI reference, that identifies this occurrence of problem.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Extension members of problem.
+    #[serde(flatten)]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Problem {
+    /// Create problem of default (`about:blank`) type with specified status code.
+    pub fn new(status: u16) -> Self {
+        Self {
+            r#type: Self::default_type(),
+            title: None,
+            status,
+            detail: None,
+            instance: None,
+            extensions: Default::default(),
+        }
+    }
+
+    pub fn with_type(mut self, r#type: impl Into<String>) -> Self {
+        self.r#type = r#type.into();
+        self
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+
+    pub fn with_extension(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.extensions.insert(name.into(), value.into());
+        self
+    }
+
+    fn default_type() -> String {
+        "about:blank".to_owned()
+    }
+
+    fn default_status() -> u16 {
+        500
+    }
+}
+
+impl EndpointError for Problem {
+    const CONTENT_TYPE: &'static str = "application/problem+json";
+
+    fn status(&self) -> u16 {
+        self.status
+    }
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.title.as_deref().unwrap_or(&self.r#type),
+            self.status
+        )?;
+
+        if let Some(detail) = &self.detail {
+            write!(f, ": {detail}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Problem {}
+
+/// Server side response with [`EndpointError`].
+///
+/// Error is serialized as JSON body of response with status code and `content-type`
+/// of error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorResponse<E>(pub E);
+
+impl<E> ErrorResponse<E> {
+    /// Unwrap returned error.
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> From<E> for ErrorResponse<E> {
+    fn from(value: E) -> Self {
+        Self(value)
+    }
+}
+
+impl<E: EndpointError> std::fmt::Display for ErrorResponse<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "endpoint error with status {}", self.0.status())
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_impl {
+    use super::{EndpointError, ErrorResponse};
+
+    use axum::http::{header, HeaderValue, StatusCode};
+    use axum::response::{IntoResponse, Response};
+
+    impl<E: EndpointError + serde::Serialize> IntoResponse for ErrorResponse<E> {
+        fn into_response(self) -> Response {
+            let status =
+                StatusCode::from_u16(self.0.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            let mut response = axum::Json(self.0).into_response();
+
+            // Serialization errors are rendered by `Json` itself
+            if response.status().is_success() {
+                *response.status_mut() = status;
+                response.headers_mut().insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static(E::CONTENT_TYPE),
+                );
+            }
+
+            response
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_web_impl {
+    use super::{EndpointError, ErrorResponse};
+
+    use actix_web::http::{header, StatusCode};
+    use actix_web::{HttpResponse, ResponseError};
+
+    impl<E> ResponseError for ErrorResponse<E>
+    where
+        E: EndpointError + serde::Serialize + std::fmt::Debug,
+    {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::from_u16(self.0.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            HttpResponse::build(self.status_code())
+                .insert_header((header::CONTENT_TYPE, E::CONTENT_TYPE))
+                .json(&self.0)
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Interceptors of requests, sent by generated clients.
+
+use crate::client_error::ClientError;
+use crate::transport::EndpointInfo;
+
+/// Cross-cutting behavior, that generated clients run around every request.
+///
+/// Interceptors are run by [`ReqwestTransport`](crate::ReqwestTransport) in order of registration.
+///
+/// ```
+/// use comfund::{ClientError, EndpointInfo, Interceptor};
+///
+/// struct Auth(String);
+///
+/// impl Interceptor for Auth {
+///     fn before(
+///         &self,
+///         _endpoint: &EndpointInfo,
+///         request: &mut reqwest::Request,
+///     ) -> Result<(), ClientError> {
+///         let value = format!("Bearer {}", self.0).parse().unwrap();
+///         request.headers_mut().insert(reqwest::header::AUTHORIZATION, value);
+///
+///         Ok(())
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync + 'static {
+    /// Inspect or mutate outgoing request. Returned error aborts request.
+    fn before(
+        &self,
+        endpoint: &EndpointInfo,
+        request: &mut reqwest::Request,
+    ) -> Result<(), ClientError> {
+        let _ = (endpoint, request);
+        Ok(())
+    }
+
+    /// Observe received response.
+    fn after(&self, endpoint: &EndpointInfo, response: &reqwest::Response) {
+        let _ = (endpoint, response);
+    }
+}
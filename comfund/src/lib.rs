@@ -17,32 +17,156 @@
 
 pub use comfund_macros::contract;
 
-#[cfg(any(feature = "reqwest"))]
+#[cfg(feature = "reqwest-blocking")]
+pub mod blocking;
+#[cfg(feature = "client")]
+pub mod client_error;
+#[cfg(feature = "client")]
+pub mod codec;
+#[cfg(any(feature = "client", feature = "axum", feature = "actix-web"))]
+pub mod cookie;
+pub mod error;
+#[cfg(feature = "reqwest")]
+pub mod interceptor;
+pub mod multipart;
+#[cfg(feature = "client")]
+pub mod request_options;
+#[cfg(feature = "client")]
+pub mod response;
+#[cfg(feature = "axum")]
+pub mod substate;
+#[cfg(feature = "client")]
+pub mod transport;
+
+pub use error::{EndpointError, ErrorResponse, Problem};
+
+#[cfg(feature = "client")]
+pub use http;
+#[cfg(feature = "client")]
 pub use paths;
 
+#[cfg(feature = "client")]
+pub use client_exports::*;
+
+#[cfg(feature = "client")]
+mod client_exports {
+    use std::borrow::Cow;
+
+    pub use crate::client_error::ClientError;
+    pub use crate::request_options::RequestOptions;
+    pub use crate::response::Response;
+    pub use crate::transport::{ClientTransport, EndpointInfo};
+
+    /// State of generated client, shared by endpoints of all contracts, that client implements.
+    ///
+    /// Endpoints of each contract are provided by generated `[contract_name]Api` trait, implemented
+    /// for every client, so that clients of inheriting contracts (at any depth) expose them too.
+    pub trait ContractClient {
+        /// Transport, that sends requests of all endpoints.
+        type Transport: ClientTransport;
+
+        /// Root url of service.
+        fn root(&self) -> &str;
+
+        fn transport(&self) -> &Self::Transport;
+
+        /// Create client from root url of service and transport.
+        fn from_parts(root: Cow<'static, str>, transport: Self::Transport) -> Self
+        where
+            Self: Sized;
+    }
+
+    pub type Result<T> = std::result::Result<T, ClientError>;
+}
+
+#[cfg(all(feature = "client", feature = "actix-web"))]
+pub use transport::ActixTransport;
+#[cfg(feature = "tower")]
+pub use transport::TowerTransport;
+
 #[cfg(feature = "reqwest")]
 pub use reqwest_exports::*;
 
 #[cfg(feature = "reqwest")]
 mod reqwest_exports {
-    #[derive(Debug)]
-    pub enum ClientError {
-        PathSerializerError(paths::path_serializer::Error),
-        Reqwest(reqwest::Error),
+    use std::borrow::Cow;
+    use std::marker::PhantomData;
+    use std::time::Duration;
+
+    use crate::{ClientError, ContractClient};
+
+    pub use crate::interceptor::Interceptor;
+    pub use crate::transport::ReqwestTransport;
+
+    /// Builder of generated clients with configured HTTP client.
+    ///
+    /// ```ignore
+    /// let client = ServiceClient::builder("http://localhost:8080")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()?;
+    /// ```
+    pub struct ClientBuilder<C> {
+        root: Cow<'static, str>,
+        http: reqwest::ClientBuilder,
+        transport: ReqwestTransport,
+        _client: PhantomData<fn() -> C>,
     }
 
-    impl From<reqwest::Error> for ClientError {
-        fn from(value: reqwest::Error) -> Self {
-            Self::Reqwest(value)
+    impl<C: ContractClient<Transport = ReqwestTransport>> ClientBuilder<C> {
+        pub fn new(root: impl Into<Cow<'static, str>>) -> Self {
+            Self {
+                root: root.into(),
+                http: reqwest::Client::builder(),
+                transport: ReqwestTransport::new(),
+                _client: PhantomData,
+            }
+        }
+
+        /// Configure underlying HTTP client.
+        pub fn http(
+            mut self,
+            configure: impl FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder,
+        ) -> Self {
+            self.http = configure(self.http);
+            self
+        }
+
+        /// Timeout of whole request, including reading of response body.
+        pub fn timeout(self, timeout: Duration) -> Self {
+            self.http(|http| http.timeout(timeout))
+        }
+
+        /// Timeout of connection establishment.
+        pub fn connect_timeout(self, timeout: Duration) -> Self {
+            self.http(|http| http.connect_timeout(timeout))
         }
-    }
 
-    impl From<::paths::path_serializer::Error> for ClientError {
-        fn from(value: ::paths::path_serializer::Error) -> Self {
-            Self::PathSerializerError(value)
+        /// Headers, sent with every request.
+        pub fn default_headers(self, headers: reqwest::header::HeaderMap) -> Self {
+            self.http(|http| http.default_headers(headers))
+        }
+
+        /// `user-agent` header, sent with every request.
+        pub fn user_agent(self, user_agent: impl Into<reqwest::header::HeaderValue>) -> Self {
+            self.http(|http| http.user_agent(user_agent))
+        }
+
+        /// Run interceptor around every request. Interceptors are run in order of registration.
+        pub fn interceptor(mut self, interceptor: impl Interceptor) -> Self {
+            self.transport = self.transport.with_interceptor(interceptor);
+            self
+        }
+
+        pub fn build(self) -> Result<C, ClientError> {
+            let http_client = self.http.build()?;
+
+            Ok(C::from_parts(
+                self.root,
+                self.transport.with_client(http_client),
+            ))
         }
     }
 }
 
-#[cfg(feature = "reqwest")]
-pub type Result<T> = std::result::Result<T, ClientError>;
+#[cfg(feature = "reqwest-blocking")]
+pub use blocking::BlockingContractClient;
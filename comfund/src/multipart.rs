@@ -0,0 +1,453 @@
+//! Framework agnostic representation of `multipart/form-data` bodies.
+//!
+//! Structures, passed as `#[param(multipart)]` endpoint params, should implement
+//! [`MultipartForm`] (usually, through derive macro of the same name). Each field of such
+//! structure is converted into one or more form parts through [`FormField`] trait.
+//!
+//! ```
+//! use comfund::multipart::{File, MultipartForm};
+//!
+//! #[derive(MultipartForm)]
+//! pub struct Upload {
+//!     title: String,
+//!     tags: Vec<String>,
+//!     description: Option<String>,
+//!     attachment: File,
+//! }
+//! ```
+//!
+//! On server side, such param is received as [`Multipart<T>`] extractor.
+
+pub use comfund_macros::MultipartForm;
+
+/// Structure, that can be sent as `multipart/form-data` request body.
+pub trait MultipartForm: Sized {
+    /// Convert structure into form parts.
+    fn into_form(self) -> Form;
+
+    /// Restore structure from received form parts.
+    fn from_form(form: Form) -> Result<Self, Error>;
+}
+
+/// Value of [`MultipartForm`] field, that can be sent as zero or more form parts.
+pub trait FormField: Sized {
+    /// Append value to the form as parts with given name.
+    fn into_parts(self, name: &str, form: &mut Form);
+
+    /// Restore value from all received parts with given name.
+    fn from_parts(name: &str, parts: Vec<Part>) -> Result<Self, Error>;
+}
+
+/// A set of `multipart/form-data` parts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Form {
+    parts: Vec<Part>,
+}
+
+impl Form {
+    /// Create empty form.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append part to the end of form.
+    pub fn push(&mut self, part: Part) {
+        self.parts.push(part);
+    }
+
+    /// Remove all parts with given name from form, preserving their order.
+    pub fn take(&mut self, name: &str) -> Vec<Part> {
+        let (taken, rest) = std::mem::take(&mut self.parts)
+            .into_iter()
+            .partition(|part| part.name == name);
+
+        self.parts = rest;
+
+        taken
+    }
+
+    /// Get all parts of the form.
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Encode form as `multipart/form-data` body.
+    ///
+    /// Returns value of `content-type` header (including boundary) and encoded body.
+    /// Boundary is chosen deterministically, so that it doesn't occur in contents of any part.
+    pub fn encode(&self) -> (String, Vec<u8>) {
+        let boundary = self.boundary();
+        let mut body = Vec::new();
+
+        for part in &self.parts {
+            body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+            body.extend_from_slice(
+                format!(
+                    "content-disposition: form-data; name=\"{}\"",
+                    escape_quoted(&part.name)
+                )
+                .as_bytes(),
+            );
+
+            if let Some(file_name) = &part.file_name {
+                body.extend_from_slice(
+                    format!("; filename=\"{}\"", escape_quoted(file_name)).as_bytes(),
+                );
+            }
+
+            body.extend_from_slice(b"\r\n");
+
+            if let Some(content_type) = &part.content_type {
+                body.extend_from_slice(format!("content-type: {content_type}\r\n").as_bytes());
+            }
+
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+
+        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+
+        (format!("multipart/form-data; boundary={boundary}"), body)
+    }
+
+    fn boundary(&self) -> String {
+        (0u64..)
+            .map(|n| format!("comfund-boundary-{n:016x}"))
+            .find(|boundary| {
+                !self.parts.iter().any(|part| {
+                    part.data
+                        .windows(boundary.len())
+                        .any(|window| window == boundary.as_bytes())
+                })
+            })
+            .unwrap()
+    }
+}
+
+/// Escape quoted value of `content-disposition` parameter.
+fn escape_quoted(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+impl IntoIterator for Form {
+    type Item = Part;
+    type IntoIter = std::vec::IntoIter<Part>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.into_iter()
+    }
+}
+
+/// Single part of `multipart/form-data` body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    /// Name of form field.
+    pub name: String,
+    /// File name (for file parts).
+    pub file_name: Option<String>,
+    /// MIME type of part contents.
+    pub content_type: Option<String>,
+    /// Raw contents of part.
+    pub data: Vec<u8>,
+}
+
+impl Part {
+    /// Create text part.
+    pub fn text(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            file_name: None,
+            content_type: None,
+            data: value.into().into_bytes(),
+        }
+    }
+}
+
+/// File part of the form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct File {
+    /// Name of file.
+    pub file_name: Option<String>,
+    /// MIME type of file.
+    pub content_type: Option<String>,
+    /// Contents of file.
+    pub data: Vec<u8>,
+}
+
+impl File {
+    /// Create file with given name, MIME type and contents.
+    pub fn new(
+        file_name: impl Into<String>,
+        content_type: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            file_name: Some(file_name.into()),
+            content_type: Some(content_type.into()),
+            data: data.into(),
+        }
+    }
+}
+
+impl FormField for File {
+    fn into_parts(self, name: &str, form: &mut Form) {
+        form.push(Part {
+            name: name.to_owned(),
+            file_name: self.file_name,
+            content_type: self.content_type,
+            data: self.data,
+        })
+    }
+
+    fn from_parts(name: &str, parts: Vec<Part>) -> Result<Self, Error> {
+        let part = single_part(name, parts)?;
+
+        Ok(Self {
+            file_name: part.file_name,
+            content_type: part.content_type,
+            data: part.data,
+        })
+    }
+}
+
+impl<T: FormField> FormField for Option<T> {
+    fn into_parts(self, name: &str, form: &mut Form) {
+        if let Some(value) = self {
+            value.into_parts(name, form)
+        }
+    }
+
+    fn from_parts(name: &str, parts: Vec<Part>) -> Result<Self, Error> {
+        if parts.is_empty() {
+            Ok(None)
+        } else {
+            T::from_parts(name, parts).map(Some)
+        }
+    }
+}
+
+impl<T: FormField> FormField for Vec<T> {
+    fn into_parts(self, name: &str, form: &mut Form) {
+        for value in self {
+            value.into_parts(name, form)
+        }
+    }
+
+    fn from_parts(name: &str, parts: Vec<Part>) -> Result<Self, Error> {
+        parts
+            .into_iter()
+            .map(|part| T::from_parts(name, vec![part]))
+            .collect()
+    }
+}
+
+macro_rules! impl_text_field {
+    ($($ty:ty),*) => {
+        $(
+            impl FormField for $ty {
+                fn into_parts(self, name: &str, form: &mut Form) {
+                    form.push(Part::text(name, self.to_string()))
+                }
+
+                fn from_parts(name: &str, parts: Vec<Part>) -> Result<Self, Error> {
+                    let part = single_part(name, parts)?;
+
+                    std::str::from_utf8(&part.data)
+                        .ok()
+                        .and_then(|text| text.parse().ok())
+                        .ok_or_else(|| Error::InvalidValue(name.to_owned()))
+                }
+            }
+        )*
+    };
+}
+
+impl_text_field!(
+    String, bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+fn single_part(name: &str, parts: Vec<Part>) -> Result<Part, Error> {
+    let mut parts = parts.into_iter();
+
+    match (parts.next(), parts.next()) {
+        (Some(part), None) => Ok(part),
+        (None, _) => Err(Error::MissingPart(name.to_owned())),
+        (Some(_), Some(_)) => Err(Error::RepeatedPart(name.to_owned())),
+    }
+}
+
+/// An error of restoring [`MultipartForm`] from received form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Required part wasn't present in form.
+    MissingPart(String),
+    /// Part, that should be single, was present more than once.
+    RepeatedPart(String),
+    /// Part contents couldn't be parsed into field value.
+    InvalidValue(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPart(name) => write!(f, "missing form part `{name}`"),
+            Self::RepeatedPart(name) => write!(f, "form part `{name}` is repeated"),
+            Self::InvalidValue(name) => write!(f, "invalid value of form part `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Server side extractor of [`MultipartForm`] body params.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Multipart<T>(pub T);
+
+impl<T> Multipart<T> {
+    /// Unwrap extracted form.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Multipart<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for Multipart<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "reqwest")]
+mod reqwest_impl {
+    use super::Form;
+
+    impl Form {
+        /// Convert form into [`reqwest`] multipart form.
+        pub fn into_reqwest(self) -> reqwest::Result<reqwest::multipart::Form> {
+            let mut output = reqwest::multipart::Form::new();
+
+            for part in self {
+                let mut reqwest_part = reqwest::multipart::Part::bytes(part.data);
+
+                if let Some(file_name) = part.file_name {
+                    reqwest_part = reqwest_part.file_name(file_name);
+                }
+
+                if let Some(content_type) = part.content_type {
+                    reqwest_part = reqwest_part.mime_str(&content_type)?;
+                }
+
+                output = output.part(part.name, reqwest_part);
+            }
+
+            Ok(output)
+        }
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_impl {
+    use super::{Form, Multipart, MultipartForm, Part};
+
+    use axum::extract::{FromRequest, Request};
+    use axum::http::StatusCode;
+    use axum::response::{IntoResponse, Response};
+
+    impl<T, S> FromRequest<S> for Multipart<T>
+    where
+        T: MultipartForm,
+        S: Send + Sync,
+    {
+        type Rejection = Response;
+
+        async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+            let mut multipart = axum::extract::Multipart::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+
+            let mut form = Form::new();
+
+            while let Some(field) = multipart
+                .next_field()
+                .await
+                .map_err(IntoResponse::into_response)?
+            {
+                let name = field.name().unwrap_or_default().to_owned();
+                let file_name = field.file_name().map(str::to_owned);
+                let content_type = field.content_type().map(str::to_owned);
+                let data = field.bytes().await.map_err(IntoResponse::into_response)?;
+
+                form.push(Part {
+                    name,
+                    file_name,
+                    content_type,
+                    data: data.to_vec(),
+                });
+            }
+
+            T::from_form(form)
+                .map(Multipart)
+                .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()).into_response())
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_web_impl {
+    use super::{Form, Multipart, MultipartForm, Part};
+
+    use actix_web::{dev::Payload, FromRequest, HttpRequest};
+    use futures_util::future::LocalBoxFuture;
+    use futures_util::StreamExt;
+
+    impl<T: MultipartForm + 'static> FromRequest for Multipart<T> {
+        type Error = actix_web::Error;
+        type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+        fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+            let mut multipart = actix_multipart::Multipart::new(req.headers(), payload.take());
+
+            Box::pin(async move {
+                let mut form = Form::new();
+
+                while let Some(field) = multipart.next().await {
+                    let mut field = field?;
+
+                    let name = field.name().unwrap_or_default().to_owned();
+                    let file_name = field
+                        .content_disposition()
+                        .and_then(|disposition| disposition.get_filename())
+                        .map(str::to_owned);
+                    let content_type = field.content_type().map(ToString::to_string);
+
+                    let mut data = vec![];
+                    while let Some(chunk) = field.next().await {
+                        data.extend_from_slice(&chunk?);
+                    }
+
+                    form.push(Part {
+                        name,
+                        file_name,
+                        content_type,
+                        data,
+                    });
+                }
+
+                T::from_form(form)
+                    .map(Multipart)
+                    .map_err(actix_web::error::ErrorBadRequest)
+            })
+        }
+    }
+}
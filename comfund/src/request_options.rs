@@ -0,0 +1,100 @@
+//! Options of single request, sent by generated clients.
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::transport::Timeout;
+
+/// Name of header, that carries idempotency key of request.
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// Options of single request, accepted by `[endpoint_name]_with` functions of generated clients.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use comfund::http::header::{HeaderName, HeaderValue};
+/// use comfund::RequestOptions;
+///
+/// let options = RequestOptions::new()
+///     .header(HeaderName::from_static("x-tenant-id"), HeaderValue::from(42))
+///     .timeout(Duration::from_secs(1))
+///     .idempotency_key(HeaderValue::from_static("3f1c9e0a"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    root: Option<Cow<'static, str>>,
+    idempotency_key: Option<HeaderValue>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add header to request. Overrides headers, set by endpoint params.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.append(name, value);
+        self
+    }
+
+    /// Add headers to request. Overrides headers, set by endpoint params.
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Timeout of request, that overrides timeout of client.
+    ///
+    /// Honored by all built-in transports. Custom transports receive it as [`Timeout`]
+    /// in extensions of request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Root url of service, that overrides root of client.
+    pub fn root(mut self, root: impl Into<Cow<'static, str>>) -> Self {
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Idempotency key, sent in [`IDEMPOTENCY_KEY`] header.
+    pub fn idempotency_key(mut self, key: HeaderValue) -> Self {
+        self.idempotency_key = Some(key);
+        self
+    }
+
+    /// Overridden root url of service.
+    pub fn root_override(&self) -> Option<&str> {
+        self.root.as_deref()
+    }
+
+    /// Apply options to encoded request.
+    ///
+    /// Timeout is kept in extensions of request as [`Timeout`], to be applied by transport.
+    pub(crate) fn apply(&self, request: &mut http::Request<Vec<u8>>) {
+        let headers = request.headers_mut();
+
+        // Values of repeated headers are all kept, replacing values set by endpoint params
+        for name in self.headers.keys() {
+            headers.remove(name);
+
+            for value in self.headers.get_all(name) {
+                headers.append(name, value.clone());
+            }
+        }
+
+        if let Some(key) = &self.idempotency_key {
+            headers.insert(IDEMPOTENCY_KEY, key.clone());
+        }
+
+        if let Some(timeout) = self.timeout {
+            request.extensions_mut().insert(Timeout(timeout));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Whole responses, returned by `[endpoint_name]_raw` functions of generated clients.
+
+use http::header::HeaderMap;
+use http::{StatusCode, Uri};
+
+/// Response of endpoint with decoded body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    status: StatusCode,
+    headers: HeaderMap,
+    url: Uri,
+    body: T,
+}
+
+impl Response<()> {
+    /// Status, headers and url of response without body.
+    ///
+    /// Url is taken from extensions of response, where it's put by transport.
+    #[doc(hidden)]
+    pub fn head<B>(response: &http::Response<B>) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+            url: response
+                .extensions()
+                .get::<Uri>()
+                .cloned()
+                .unwrap_or_default(),
+            body: (),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn with_body<T>(self, body: T) -> Response<T> {
+        self.map(|()| body)
+    }
+}
+
+impl<T> Response<T> {
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Final url of response (after redirects, if followed by transport).
+    pub fn url(&self) -> &Uri {
+        &self.url
+    }
+
+    pub fn body(&self) -> &T {
+        &self.body
+    }
+
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    /// Map decoded body, keeping status, headers and url.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            url: self.url,
+            body: f(self.body),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! States of inherited contracts, served by `axum` router of inheriting one.
+
+/// State of inherited contract, derived from state of inheriting one.
+///
+/// Implemented for every state, that inherited state can be extracted from with
+/// [`FromRef`](axum::extract::FromRef). Required by service traits of inheriting contracts,
+/// so that routers of contracts are composable at any depth.
+pub trait Substate<T> {
+    fn substate(&self) -> T;
+}
+
+impl<S, T: axum::extract::FromRef<S>> Substate<T> for S {
+    fn substate(&self) -> T {
+        T::from_ref(self)
+    }
+}
@@ -0,0 +1,485 @@
+//! Transports, that send requests of generated clients.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::client_error::ClientError;
+use crate::request_options::RequestOptions;
+
+#[cfg(feature = "actix-web")]
+pub use actix_impl::ActixTransport;
+#[cfg(feature = "reqwest-blocking")]
+pub(crate) use reqwest_impl::with_url;
+#[cfg(feature = "reqwest")]
+pub use reqwest_impl::ReqwestTransport;
+#[cfg(feature = "tower")]
+pub use tower_impl::TowerTransport;
+
+/// Metadata of endpoint, that sends request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointInfo {
+    /// Name of contract, that defines endpoint.
+    pub contract: &'static str,
+    /// Name of endpoint function.
+    pub endpoint: &'static str,
+    pub method: http::Method,
+    /// Path template of endpoint (including prefix of contract), i.e. `/api/users/{id}`.
+    pub path: &'static str,
+}
+
+/// Sends encoded requests of endpoints and receives whole responses.
+///
+/// Generated clients are generic over transport and use `ReqwestTransport` by default.
+/// Custom transports can send requests with other HTTP clients, serve them in process
+/// or record them.
+///
+/// ```
+/// use comfund::http;
+/// use comfund::{ClientError, ClientTransport, EndpointInfo};
+///
+/// struct Teapot;
+///
+/// impl ClientTransport for Teapot {
+///     async fn send(
+///         &self,
+///         _endpoint: &EndpointInfo,
+///         _request: http::Request<Vec<u8>>,
+///     ) -> Result<http::Response<Vec<u8>>, ClientError> {
+///         let mut response = http::Response::new(Vec::new());
+///         *response.status_mut() = http::StatusCode::IM_A_TEAPOT;
+///
+///         Ok(response)
+///     }
+/// }
+/// ```
+pub trait ClientTransport: Send + Sync {
+    /// Send request of endpoint and receive response with whole body.
+    ///
+    /// Transport should respect [`Timeout`] in extensions of request (if present) and may put
+    /// final url of response (after redirects) into its extensions as [`http::Uri`].
+    fn send(
+        &self,
+        endpoint: &EndpointInfo,
+        request: http::Request<Vec<u8>>,
+    ) -> impl Future<Output = Result<http::Response<Vec<u8>>, ClientError>> + Send;
+}
+
+/// Timeout of single request, kept in extensions of request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeout(pub Duration);
+
+/// Send request through transport with options applied.
+#[doc(hidden)]
+pub async fn send<T: ClientTransport>(
+    transport: &T,
+    endpoint: &EndpointInfo,
+    options: &RequestOptions,
+    mut request: http::Request<Vec<u8>>,
+) -> Result<http::Response<Vec<u8>>, ClientError> {
+    options.apply(&mut request);
+
+    let url = request.uri().clone();
+    let mut response = transport.send(endpoint, request).await?;
+
+    if response.extensions().get::<http::Uri>().is_none() {
+        response.extensions_mut().insert(url);
+    }
+
+    Ok(response)
+}
+
+#[cfg(feature = "reqwest")]
+mod reqwest_impl {
+    use std::sync::{Arc, OnceLock};
+
+    use super::{ClientTransport, EndpointInfo, Timeout};
+    use crate::client_error::ClientError;
+    use crate::interceptor::Interceptor;
+
+    /// Transport on top of [`reqwest::Client`], that runs [`Interceptor`]s around every request.
+    #[derive(Default)]
+    pub struct ReqwestTransport {
+        // Lazily initialized, so that transport can be created in const context
+        http_client: OnceLock<reqwest::Client>,
+        interceptors: Vec<Arc<dyn Interceptor>>,
+    }
+
+    impl ReqwestTransport {
+        pub const fn new() -> Self {
+            Self {
+                http_client: OnceLock::new(),
+                interceptors: Vec::new(),
+            }
+        }
+
+        /// Send requests with specified HTTP client.
+        pub fn with_client(self, http_client: reqwest::Client) -> Self {
+            Self {
+                http_client: OnceLock::from(http_client),
+                ..self
+            }
+        }
+
+        /// Run interceptor around every request. Interceptors are run in order of registration.
+        pub fn with_interceptor(mut self, interceptor: impl Interceptor) -> Self {
+            self.interceptors.push(Arc::new(interceptor));
+            self
+        }
+
+        /// HTTP client, that sends requests.
+        pub fn http_client(&self) -> &reqwest::Client {
+            self.http_client.get_or_init(reqwest::Client::new)
+        }
+
+        /// Interceptors, that are run around every request.
+        pub fn interceptors(&self) -> &[Arc<dyn Interceptor>] {
+            &self.interceptors
+        }
+
+        /// Unsent request of HTTP client from encoded request.
+        #[doc(hidden)]
+        pub fn prepare(
+            &self,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<reqwest::RequestBuilder, ClientError> {
+            let request = into_reqwest(request)?;
+
+            Ok(reqwest::RequestBuilder::from_parts(
+                self.http_client().clone(),
+                request,
+            ))
+        }
+    }
+
+    impl std::fmt::Debug for ReqwestTransport {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ReqwestTransport")
+                .field("http_client", &self.http_client)
+                .field("interceptors", &self.interceptors.len())
+                .finish()
+        }
+    }
+
+    impl ClientTransport for ReqwestTransport {
+        async fn send(
+            &self,
+            endpoint: &EndpointInfo,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, ClientError> {
+            let mut request = into_reqwest(request)?;
+
+            for interceptor in &self.interceptors {
+                interceptor.before(endpoint, &mut request)?;
+            }
+
+            let response = self
+                .http_client()
+                .execute(request)
+                .await
+                .map_err(|err| ClientError::Transport(Box::new(err)))?;
+
+            for interceptor in &self.interceptors {
+                interceptor.after(endpoint, &response);
+            }
+
+            let mut head = http::Response::new(());
+            *head.status_mut() = response.status();
+            *head.version_mut() = response.version();
+            *head.headers_mut() = response.headers().clone();
+            let url = response.url().clone();
+
+            let body = response
+                .bytes()
+                .await
+                .map_err(|err| ClientError::Transport(Box::new(err)))?;
+
+            Ok(with_url(head, &url).map(|()| body.to_vec()))
+        }
+    }
+
+    /// Convert encoded request into request of [`reqwest::Client`].
+    fn into_reqwest(request: http::Request<Vec<u8>>) -> Result<reqwest::Request, ClientError> {
+        let timeout = request.extensions().get::<Timeout>().copied();
+        let mut request = reqwest::Request::try_from(request)
+            .map_err(|err| ClientError::Encode(Box::new(err)))?;
+
+        if let Some(Timeout(timeout)) = timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+
+        Ok(request)
+    }
+
+    /// Put final url of response into its extensions.
+    pub(crate) fn with_url<B>(
+        mut response: http::Response<B>,
+        url: &reqwest::Url,
+    ) -> http::Response<B> {
+        if let Ok(url) = url.as_str().parse::<http::Uri>() {
+            response.extensions_mut().insert(url);
+        }
+
+        response
+    }
+}
+
+#[cfg(feature = "tower")]
+mod tower_impl {
+    use std::marker::PhantomData;
+
+    use bytes::Bytes;
+    use http_body_util::{BodyExt, Full};
+    use tower_service::Service;
+
+    use super::{ClientTransport, EndpointInfo, Timeout};
+    use crate::client_error::{BoxError, ClientError};
+
+    /// Transport, that drives requests through [`tower_service::Service`], i.e. `axum::Router`
+    /// or HTTP client, wrapped into `tower` layers (retries, timeouts, concurrency limits, etc).
+    ///
+    /// Service is cloned for every request. [`Timeout`] of request bounds both the call
+    /// of service and receiving of response body (with timer of `tokio`), and is kept in
+    /// extensions of request for layers of service as well.
+    pub struct TowerTransport<S, B = Full<Bytes>> {
+        service: S,
+        _body: PhantomData<fn(B)>,
+    }
+
+    impl<S> TowerTransport<S> {
+        /// Create transport, that sends requests with [`Full`] bodies.
+        pub fn new(service: S) -> Self {
+            Self::with_body(service)
+        }
+    }
+
+    impl<S, B> TowerTransport<S, B> {
+        /// Create transport, that sends requests with bodies of type `B`.
+        pub fn with_body(service: S) -> Self {
+            Self {
+                service,
+                _body: PhantomData,
+            }
+        }
+
+        pub fn service(&self) -> &S {
+            &self.service
+        }
+    }
+
+    impl<S: Clone, B> Clone for TowerTransport<S, B> {
+        fn clone(&self) -> Self {
+            Self::with_body(self.service.clone())
+        }
+    }
+
+    impl<S: std::fmt::Debug, B> std::fmt::Debug for TowerTransport<S, B> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("TowerTransport")
+                .field("service", &self.service)
+                .finish()
+        }
+    }
+
+    impl<S, B, R> ClientTransport for TowerTransport<S, B>
+    where
+        S: Service<http::Request<B>, Response = http::Response<R>> + Clone + Send + Sync,
+        S::Error: Into<BoxError>,
+        S::Future: Send,
+        B: From<Vec<u8>> + Send,
+        R: http_body::Body + Send,
+        R::Data: Send,
+        R::Error: Into<BoxError>,
+    {
+        async fn send(
+            &self,
+            _endpoint: &EndpointInfo,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, ClientError> {
+            match request.extensions().get::<Timeout>().copied() {
+                Some(Timeout(timeout)) => tokio::time::timeout(timeout, self.call_service(request))
+                    .await
+                    .map_err(|elapsed| ClientError::Transport(Box::new(elapsed)))?,
+                None => self.call_service(request).await,
+            }
+        }
+    }
+
+    impl<S, B> TowerTransport<S, B> {
+        async fn call_service<R>(
+            &self,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, ClientError>
+        where
+            S: Service<http::Request<B>, Response = http::Response<R>> + Clone,
+            S::Error: Into<BoxError>,
+            B: From<Vec<u8>>,
+            R: http_body::Body,
+            R::Error: Into<BoxError>,
+        {
+            let mut service = self.service.clone();
+
+            std::future::poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(|err| ClientError::Transport(err.into()))?;
+
+            let response = service
+                .call(request.map(B::from))
+                .await
+                .map_err(|err| ClientError::Transport(err.into()))?;
+
+            let (parts, body) = response.into_parts();
+            let body = body
+                .collect()
+                .await
+                .map_err(|err| ClientError::Transport(err.into()))?
+                .to_bytes();
+
+            Ok(http::Response::from_parts(parts, body.to_vec()))
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+mod actix_impl {
+    use std::rc::Rc;
+
+    use actix_web::dev::Service;
+    use actix_web::http::Method;
+    use actix_web::web::ServiceConfig;
+    use actix_web::{test, App, HttpResponse};
+    use tokio::sync::{mpsc, oneshot};
+
+    use super::{ClientTransport, EndpointInfo, Timeout};
+    use crate::client_error::ClientError;
+
+    type Reply = oneshot::Sender<Result<http::Response<Vec<u8>>, ClientError>>;
+
+    /// Transport, that serves requests in process with `actix_web::App`, without opening
+    /// a socket. Requests go through the whole stack of extractors and responders of app.
+    ///
+    /// Since apps of `actix-web` aren't thread-safe, app is run on a dedicated thread with
+    /// its own runtime, which is stopped, when transport is dropped. [`Timeout`] of request
+    /// is applied on that runtime as well.
+    pub struct ActixTransport {
+        requests: mpsc::UnboundedSender<(http::Request<Vec<u8>>, Reply)>,
+    }
+
+    impl ActixTransport {
+        /// Create transport, that serves requests with app, configured by `configure`
+        /// (i.e. generated `configure_[contract_name]` function).
+        pub fn new<F>(configure: F) -> Self
+        where
+            F: FnOnce(&mut ServiceConfig) + Send + 'static,
+        {
+            let (requests, mut receiver) =
+                mpsc::unbounded_channel::<(http::Request<Vec<u8>>, Reply)>();
+
+            std::thread::spawn(move || {
+                actix_web::rt::System::new().block_on(async move {
+                    let app = Rc::new(test::init_service(App::new().configure(configure)).await);
+
+                    while let Some((request, reply)) = receiver.recv().await {
+                        let app = Rc::clone(&app);
+
+                        actix_web::rt::spawn(async move {
+                            let timeout = request.extensions().get::<Timeout>().copied();
+                            let response = async {
+                                let request = into_actix(request)?.to_request();
+
+                                // Errors are turned into responses, as by server of `actix-web`
+                                let response = match app.call(request).await {
+                                    Ok(response) => response.into_parts().1,
+                                    Err(err) => err.error_response(),
+                                };
+
+                                from_actix(response).await
+                            };
+
+                            let response = match timeout {
+                                Some(Timeout(timeout)) => {
+                                    actix_web::rt::time::timeout(timeout, response)
+                                        .await
+                                        .unwrap_or_else(|elapsed| {
+                                            Err(ClientError::Transport(Box::new(elapsed)))
+                                        })
+                                }
+                                None => response.await,
+                            };
+
+                            // Caller may have given up on response already
+                            let _ = reply.send(response);
+                        });
+                    }
+                });
+            });
+
+            Self { requests }
+        }
+    }
+
+    impl std::fmt::Debug for ActixTransport {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ActixTransport").finish_non_exhaustive()
+        }
+    }
+
+    impl ClientTransport for ActixTransport {
+        async fn send(
+            &self,
+            _endpoint: &EndpointInfo,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, ClientError> {
+            let (reply, response) = oneshot::channel();
+
+            self.requests
+                .send((request, reply))
+                .map_err(|_| stopped())?;
+
+            response.await.map_err(|_| stopped())?
+        }
+    }
+
+    fn stopped() -> ClientError {
+        ClientError::Transport("actix-web app isn't running".into())
+    }
+
+    /// Convert encoded request into request of `actix-web` (which depends on older `http`).
+    fn into_actix(request: http::Request<Vec<u8>>) -> Result<test::TestRequest, ClientError> {
+        let (parts, body) = request.into_parts();
+        let method = Method::from_bytes(parts.method.as_str().as_bytes())
+            .map_err(|err| ClientError::Transport(Box::new(err)))?;
+
+        let mut request = test::TestRequest::default()
+            .method(method)
+            .uri(&parts.uri.to_string())
+            .set_payload(body);
+
+        for (name, value) in &parts.headers {
+            request = request.append_header((name.as_str(), value.as_bytes()));
+        }
+
+        Ok(request)
+    }
+
+    async fn from_actix(response: HttpResponse) -> Result<http::Response<Vec<u8>>, ClientError> {
+        let mut head = http::Response::new(());
+        *head.status_mut() = http::StatusCode::from_u16(response.status().as_u16())
+            .map_err(|err| ClientError::Transport(Box::new(err)))?;
+
+        for (name, value) in response.headers() {
+            let name = http::HeaderName::from_bytes(name.as_str().as_bytes())
+                .map_err(|err| ClientError::Transport(Box::new(err)))?;
+            let value = http::HeaderValue::from_bytes(value.as_bytes())
+                .map_err(|err| ClientError::Transport(Box::new(err)))?;
+
+            head.headers_mut().append(name, value);
+        }
+
+        // Errors of boxed bodies aren't thread-safe
+        let body = actix_web::body::to_bytes(response.into_body())
+            .await
+            .map_err(|err| ClientError::Transport(err.to_string().into()))?;
+
+        Ok(head.map(|()| body.to_vec()))
+    }
+}
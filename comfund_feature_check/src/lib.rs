@@ -0,0 +1,48 @@
+//! Contract with every kind of generated inputs, that should compile with any single feature
+//! of `comfund` enabled (or with none of them), i.e.
+//!
+//! ```sh
+//! cargo build -p comfund_feature_check --no-default-features --features reqwest-blocking
+//! ```
+//!
+//! Tests of `comfund_tests` always enable all features, so gaps in feature gates of
+//! generated code are only caught here.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page {
+    pub offset: u32,
+    pub limit: u32,
+}
+
+#[comfund::contract(prefix = "/api", content_type = "application/json")]
+pub trait Catalog {
+    #[endpoint(get, "/items/{shop}/{id}")]
+    fn item(#[param(path)] shop: String, #[param(path)] id: u32) -> String;
+
+    #[endpoint(get, "/items")]
+    fn search(
+        #[param(query)] text: String,
+        #[param(query, flatten)] page: Page,
+        #[param(header = "X-Tenant-Id")] tenant: u32,
+    ) -> Vec<String>;
+
+    #[endpoint(get, "/count")]
+    fn count(#[param(query)] text: String) -> u32;
+
+    #[endpoint(post, "/items")]
+    fn create(#[param(json)] name: String) -> u32;
+}
+
+/// Client of contract is generated with `tower` transport alone, even in `static` mode.
+#[cfg(feature = "tower")]
+pub async fn count_with_tower<S>(service: S, text: String) -> comfund::Result<u32>
+where
+    comfund::TowerTransport<S>: comfund::ClientTransport,
+{
+    let client =
+        CatalogClient::with_transport(&"http://catalog", comfund::TowerTransport::new(service));
+
+    client.count(text).await
+}
@@ -0,0 +1,323 @@
+//! Sans-IO codec of contract, that encodes calls of endpoints into `http::Request`s and
+//! decodes `http::Response`s into their return values, without sending anything.
+//!
+//! Generated clients are built on top of it, but it can also be used directly with custom
+//! runtimes or to test wire format of endpoints.
+
+use crate::contract::content_type::ContentType;
+use crate::contract::endpoint::Endpoint;
+use crate::contract::method::Method;
+use crate::contract::transport::Transport;
+use crate::Contract;
+use comfund_paths::path_template::{PathTemplate, Segment};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse_quote;
+
+use super::{params, ret_ty};
+
+pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
+    let fns = contract.endpoints.iter().map(|ep| {
+        let encode_fn = impl_encode(&contract.id, ep);
+        let decode_fn = impl_decode(&contract.id, ep);
+
+        quote! {
+            #encode_fn
+
+            #decode_fn
+        }
+    });
+
+    // Codecs of inherited contracts are reexported as is
+    let inherited = contract.supertraits.iter().map(|supertrait| {
+        let module = supertrait.item_path(1, None, &format_ident!("codec"));
+
+        quote! {
+            pub use #module::*;
+        }
+    });
+
+    quote! {
+        #[cfg(any(
+            feature = "client",
+            feature = "reqwest",
+            feature = "reqwest-blocking",
+            feature = "tower"
+        ))]
+        pub mod codec {
+            use super::*;
+
+            #(#fns)*
+
+            #(#inherited)*
+        }
+    }
+}
+
+pub fn encode_ident(ep: &Endpoint) -> syn::Ident {
+    format_ident!("encode_{}", ep.id)
+}
+
+pub fn decode_ident(ep: &Endpoint) -> syn::Ident {
+    format_ident!("decode_{}", ep.id)
+}
+
+/// Function, that encodes call of endpoint into request to service at `root`.
+fn impl_encode(contract_id: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let doc = format!(
+        " Encode call of [`{1}`](super::{0}::{1}) into request to service at `root`.",
+        contract_id, ep.id
+    );
+    let name = encode_ident(ep);
+    let root = format_ident!("root");
+    let params = params(ep);
+    let request = encode_expr(&root, ep);
+
+    quote! {
+        #[doc = #doc]
+        pub fn #name(
+            #root: &str,
+            #params
+        ) -> ::comfund::Result<::comfund::http::Request<::std::vec::Vec<u8>>> {
+            #request
+        }
+    }
+}
+
+/// Function, that decodes response of endpoint into its return value.
+fn impl_decode(contract_id: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let doc = format!(
+        " Decode response of [`{1}`](super::{0}::{1}) into its return value.",
+        contract_id, ep.id
+    );
+    let name = decode_ident(ep);
+    let ret_ty = ret_ty(ep);
+
+    // Only declared status is treated as success, if present
+    let is_success = match ep.meta.options().status() {
+        Some(status) => quote! { parts.status.as_u16() == #status },
+        None => quote! { parts.status.is_success() },
+    };
+
+    let decoded = match ep.meta.options().content_type.clone().unwrap_or_default() {
+        ContentType::ApplicationJson => quote! { ::comfund::codec::decode_json(&body)? },
+        ContentType::TextPlain => quote! { ::comfund::codec::decode_text(&body)? },
+    };
+
+    let (success, failure) = match &ep.err {
+        // Errors are decoded from bodies of responses with other statuses
+        Some(err) => (
+            quote! { ::std::result::Result::Ok(#decoded) },
+            quote! {
+                ::std::result::Result::Err(::comfund::ClientError::decode::<#err>(&parts, &body)?)
+            },
+        ),
+        None => (
+            decoded,
+            quote! {
+                return ::std::result::Result::Err(::comfund::ClientError::unexpected(&parts, &body))
+            },
+        ),
+    };
+
+    quote! {
+        #[doc = #doc]
+        pub fn #name(
+            response: ::comfund::http::Response<::std::vec::Vec<u8>>,
+        ) -> ::comfund::Result<#ret_ty> {
+            let (parts, body) = response.into_parts();
+
+            let body = if #is_success {
+                #success
+            } else {
+                #failure
+            };
+
+            ::std::result::Result::Ok(body)
+        }
+    }
+}
+
+pub(super) fn method_path(ep: &Endpoint) -> syn::Path {
+    match ep.meta.method() {
+        Method::Get => parse_quote!(::comfund::http::Method::GET),
+        Method::Post => parse_quote!(::comfund::http::Method::POST),
+        Method::Delete => parse_quote!(::comfund::http::Method::DELETE),
+        Method::Put => parse_quote!(::comfund::http::Method::PUT),
+        Method::Patch => parse_quote!(::comfund::http::Method::PATCH),
+    }
+}
+
+/// Encoded request with method, url, query, headers and body of endpoint applied.
+fn encode_expr(root: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let method = method_path(ep);
+    let path_params = path_expr(root, ep);
+    let query_params = query_expr(ep).map(|expr| quote! { .query(&#expr)? });
+    let header_params = header_expr(ep);
+    let cookie_params = cookie_expr(ep);
+    let body_params = body_expr(ep);
+
+    quote! {
+        ::comfund::codec::RequestEncoder::new(#method, #path_params)
+            #query_params
+            #header_params
+            #cookie_params
+            #body_params
+            .build()
+    }
+}
+
+fn path_expr(root: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let inputs = if let Some(inputs) = ep.path_inputs.as_ref() {
+        inputs
+    } else {
+        let path_lit = &ep.route;
+
+        return quote! {
+            format!("{}{}", #root, #path_lit)
+        };
+    };
+
+    let path_span = ep.route.span();
+    let path = ep.route.value();
+
+    // Template correctness validated in endpoint
+    let template = PathTemplate::new(&path).unwrap();
+
+    let segments = template.segments().iter().map(|seg| match seg {
+        Segment::Capture(cap) => {
+            let lit = syn::LitStr::new(cap, path_span);
+            quote! {
+                ::comfund::paths::Segment::Capture(#lit)
+            }
+        }
+        Segment::Static(lit) => {
+            let lit = syn::LitStr::new(lit, path_span);
+            quote! {
+                ::comfund::paths::Segment::Static(#lit)
+            }
+        }
+    });
+
+    let idents = template
+        .idents()
+        .iter()
+        .map(|ident| syn::LitStr::new(ident, path_span));
+
+    let wildcard = if let Some(ident) = template.wildcard() {
+        let lit = syn::LitStr::new(ident, path_span);
+
+        quote! {Some(#lit)}
+    } else {
+        quote! {None}
+    };
+
+    let template_id = format_ident!("______TEMPLATE");
+
+    let template_const = quote! {
+        const #template_id: ::comfund::paths::PathTemplate::<'static> = ::comfund::paths::PathTemplate::new_static(
+            &[
+                #(#segments),*
+            ],
+            &[
+                #(#idents),*
+            ],
+            #wildcard
+        );
+    };
+
+    let inputs_init = if inputs.is_flat() {
+        let name = &inputs.params.first().unwrap().name;
+
+        quote! {
+            #name
+        }
+    } else {
+        let query_struct_init = inputs.initializator(None).unwrap();
+
+        quote! {
+            #query_struct_init
+        }
+    };
+
+    quote! {
+        {
+            #template_const
+            format!("{}{}", #root, ::comfund::paths::serialize(&#template_id, &#inputs_init)?)
+        }
+    }
+}
+
+fn query_expr(ep: &Endpoint) -> Option<impl ToTokens> {
+    let inputs = ep.query_inputs.as_ref()?;
+
+    if inputs.is_flat() {
+        let name = &inputs.params.first().unwrap().name;
+
+        Some(quote! {
+            #name
+        })
+    } else {
+        let query_struct_init = inputs.initializator(None);
+
+        Some(quote! {
+            #query_struct_init
+        })
+    }
+}
+
+fn header_expr(ep: &Endpoint) -> Option<impl ToTokens> {
+    let inputs = ep.header_inputs.as_ref()?;
+
+    let headers = inputs.params.iter().map(|param| {
+        let name = &param.name;
+        let header = match param.meta.transport() {
+            Transport::Header(header) => header,
+            _ => unreachable!("Unexpected transport kind of header argument"),
+        };
+
+        quote! {
+            .header(#header, ::std::string::ToString::to_string(&#name))?
+        }
+    });
+
+    Some(quote! {
+        #(#headers)*
+    })
+}
+
+fn cookie_expr(ep: &Endpoint) -> Option<impl ToTokens> {
+    let inputs = ep.cookie_inputs.as_ref()?;
+
+    let cookies = inputs.params.iter().map(|param| {
+        let name = &param.name;
+        let cookie = match param.meta.transport() {
+            Transport::Cookie(cookie) => cookie,
+            _ => unreachable!("Unexpected transport kind of cookie argument"),
+        };
+
+        quote! {
+            (#cookie, ::std::string::ToString::to_string(&#name))
+        }
+    });
+
+    Some(quote! {
+        .header("cookie", ::comfund::cookie::encode([#(#cookies),*]))?
+    })
+}
+
+fn body_expr(ep: &Endpoint) -> Option<impl ToTokens> {
+    let param = ep.body_param.as_ref()?;
+    let param_id = &param.name;
+
+    let ret = match param.meta.transport() {
+        Transport::Body => quote! { .body(#param_id) },
+        Transport::Json => quote! { .json(&#param_id)? },
+        Transport::Form => quote! { .form(&#param_id)? },
+        Transport::Multipart => quote! {
+            .multipart(::comfund::multipart::MultipartForm::into_form(#param_id))?
+        },
+        _ => unreachable!("Unexpected transport kind of body argument"),
+    };
+
+    Some(ret)
+}
@@ -1,13 +1,348 @@
 use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::parse_quote;
 
+use crate::contract::endpoint::Endpoint;
+use crate::contract::param::Param;
 use crate::contract::Contract;
+use codec::{decode_ident, encode_ident, method_path};
 
+mod codec;
 mod reqwest;
+mod transport;
 
 pub fn implement(contract: &Contract) -> TokenStream {
     let mut stream = TokenStream::new();
 
+    stream.extend(codec::implement(contract));
+    stream.extend(transport::implement(contract));
     stream.extend(reqwest::implement(contract));
 
     stream
 }
+
+/// Endpoint params as function arguments.
+pub(crate) fn params(ep: &Endpoint) -> proc_macro2::TokenStream {
+    let path_params = ep.path_inputs.as_ref().map_or(quote! {}, |inputs| {
+        let params = inputs.params.iter().map(Param::as_function_argument);
+        quote!(#(#params,)*)
+    });
+
+    let query_params = ep.query_inputs.as_ref().map_or(quote! {}, |inputs| {
+        let params = inputs.params.iter().map(Param::as_function_argument);
+        quote!(#(#params,)*)
+    });
+
+    let header_params = ep.header_inputs.as_ref().map_or(quote! {}, |inputs| {
+        let params = inputs.params.iter().map(Param::as_function_argument);
+        quote!(#(#params,)*)
+    });
+
+    let cookie_params = ep.cookie_inputs.as_ref().map_or(quote! {}, |inputs| {
+        let params = inputs.params.iter().map(Param::as_function_argument);
+        quote!(#(#params,)*)
+    });
+
+    let body_param = ep.body_param.as_ref().map(Param::as_function_argument);
+
+    quote! { #path_params #query_params #header_params #cookie_params #body_param }
+}
+
+/// Names of endpoint function arguments in order of their appearance in signature.
+pub(crate) fn arg_names(ep: &Endpoint) -> impl Iterator<Item = &syn::Ident> {
+    [
+        &ep.path_inputs,
+        &ep.query_inputs,
+        &ep.header_inputs,
+        &ep.cookie_inputs,
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(|inputs| &inputs.params)
+    .chain(&ep.body_param)
+    .map(|param| &param.name)
+}
+
+/// Type, returned by endpoint (with its errors, if declared).
+pub(crate) fn ret_ty(ep: &Endpoint) -> TokenStream {
+    match &ep.err {
+        Some(err) => {
+            let ret = &ep.ret;
+            quote!(::std::result::Result<#ret, #err>)
+        }
+        None => ep.ret.to_token_stream(),
+    }
+}
+
+/// Flavor of `reqwest` API, used by generated client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    /// `reqwest::Client` with async endpoint functions
+    Async,
+    /// `reqwest::blocking::Client` with synchronous endpoint functions
+    Blocking,
+}
+
+impl Backend {
+    /// Module of `reqwest`, that contains client and request builder of backend.
+    fn module(self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Async => quote! { ::reqwest },
+            Self::Blocking => quote! { ::reqwest::blocking },
+        }
+    }
+
+    fn asyncness(self) -> Option<proc_macro2::TokenStream> {
+        matches!(self, Self::Async).then(|| quote! { async })
+    }
+
+    fn awaited(self) -> Option<proc_macro2::TokenStream> {
+        matches!(self, Self::Async).then(|| quote! { .await })
+    }
+}
+
+/// Functions, generated for every endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Variant {
+    /// Returns decoded body
+    Plain,
+    /// Returns decoded body and accepts options of request
+    With,
+    /// Returns whole response
+    Raw,
+    /// Returns whole response and accepts options of request
+    RawWith,
+}
+
+impl Variant {
+    const ALL: [Self; 4] = [Self::Plain, Self::With, Self::Raw, Self::RawWith];
+
+    fn ident(self, ep: &Endpoint) -> syn::Ident {
+        match self {
+            Self::Plain => ep.id.clone(),
+            Self::With => format_ident!("{}_with", ep.id),
+            Self::Raw => format_ident!("{}_raw", ep.id),
+            Self::RawWith => format_ident!("{}_raw_with", ep.id),
+        }
+    }
+
+    fn has_options(self) -> bool {
+        matches!(self, Self::With | Self::RawWith)
+    }
+
+    fn is_raw(self) -> bool {
+        matches!(self, Self::Raw | Self::RawWith)
+    }
+
+    /// Doc of function, that links to the plain one, documented with endpoint attributes.
+    fn doc(self, ep: &Endpoint, scope: &str) -> Option<String> {
+        let suffix = match self {
+            Self::Plain => return None,
+            Self::With => "with options of this request",
+            Self::Raw => "that returns whole response",
+            Self::RawWith => "that returns whole response, with options of this request",
+        };
+
+        Some(format!(" [`{0}`]({scope}{0}), {suffix}.", ep.id))
+    }
+
+    /// Body of function, that delegates to `RawWith` variant, called through `raw_with`.
+    fn delegate(
+        self,
+        raw_with: proc_macro2::TokenStream,
+        args: &[&syn::Ident],
+        backend: Backend,
+    ) -> proc_macro2::TokenStream {
+        let options = if self.has_options() {
+            quote! { request_options }
+        } else {
+            quote! { &::std::default::Default::default() }
+        };
+        let awaited = backend.awaited();
+        let call = quote! { #raw_with(#options, #(#args),*)#awaited };
+
+        if self.is_raw() {
+            call
+        } else {
+            quote! { #call.map(::comfund::Response::into_body) }
+        }
+    }
+}
+
+/// Generate all variants of endpoint function with bodies, provided by `body`.
+fn endpoint_fns(
+    ep: &Endpoint,
+    with_reciever: bool,
+    vis: Option<proc_macro2::TokenStream>,
+    scope: &str,
+    backend: Backend,
+    body: impl Fn(Variant) -> proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let fns = Variant::ALL.into_iter().map(|variant| {
+        let sig = sig(ep, with_reciever, variant, backend);
+        let attrs = match variant.doc(ep, scope) {
+            Some(doc) => quote! { #[doc = #doc] },
+            None => {
+                let attrs = ep.attrs.iter();
+                quote! { #(#attrs)* }
+            }
+        };
+        let body = body(variant);
+
+        quote! {
+            #attrs
+            #vis #sig {
+                #body
+            }
+        }
+    });
+
+    quote! { #(#fns)* }
+}
+
+fn sig(ep: &Endpoint, with_reciever: bool, variant: Variant, backend: Backend) -> impl ToTokens {
+    let params = params(ep);
+    let ep_name = variant.ident(ep);
+    let options = variant
+        .has_options()
+        .then(|| quote! { request_options: &::comfund::RequestOptions, });
+
+    let ret_ty = ret_ty(ep);
+    let ret_ty = if variant.is_raw() {
+        quote!(::comfund::Response<#ret_ty>)
+    } else {
+        ret_ty
+    };
+
+    let reciever = if with_reciever {
+        Some(quote! { &self, })
+    } else {
+        None
+    };
+
+    let asyncness = backend.asyncness();
+
+    quote! {
+        #asyncness fn #ep_name(#reciever #options #params) -> ::comfund::Result<#ret_ty>
+    }
+}
+
+fn prepare_ident(ep: &Endpoint) -> syn::Ident {
+    format_ident!("prepare_{}", ep.id)
+}
+
+/// Signature of function, that returns unsent request of endpoint.
+fn prepare_sig(ep: &Endpoint, with_reciever: bool, backend: Backend) -> impl ToTokens {
+    let params = params(ep);
+    let ep_name = prepare_ident(ep);
+    let reciever = with_reciever.then(|| quote! { &self, });
+    let module = backend.module();
+
+    quote! {
+        fn #ep_name(#reciever #params) -> ::comfund::Result<#module::RequestBuilder>
+    }
+}
+
+fn prepare_doc(ep: &Endpoint, scope: &str) -> String {
+    format!(
+        " Unsent request of [`{0}`]({scope}{0}) with url, query, headers and body applied.",
+        ep.id
+    )
+}
+
+/// Expressions, that provide state of client to endpoint bodies.
+#[derive(Clone)]
+struct ClientState {
+    root: syn::Expr,
+    /// Transport of async clients or HTTP client of blocking ones
+    transport: syn::Expr,
+    /// Codec module of contract, relative to module of client
+    codec: syn::Path,
+}
+
+impl ClientState {
+    /// State of client, generated in module `depth` levels below module of contract.
+    fn new(depth: usize, root: syn::Expr, transport: syn::Expr) -> Self {
+        let supers = std::iter::repeat_n(quote! { super:: }, depth);
+
+        Self {
+            root,
+            transport,
+            codec: parse_quote! { #(#supers)* codec },
+        }
+    }
+}
+
+fn impl_body(
+    contract_id: &syn::Ident,
+    state: ClientState,
+    ep: &Endpoint,
+    backend: Backend,
+) -> impl ToTokens {
+    let ClientState {
+        root,
+        transport,
+        codec,
+    } = state;
+    let root: syn::Expr = parse_quote! { request_options.root_override().unwrap_or(#root) };
+
+    let method = method_path(ep);
+    let request = encode_call(&codec, root, ep);
+    let decode_fn = decode_ident(ep);
+
+    let contract_name = contract_id.to_string();
+    let ep_name = ep.id.to_string();
+    let route = &ep.route;
+
+    let response = match backend {
+        Backend::Async => quote! {
+            ::comfund::transport::send(
+                #transport,
+                &::comfund::EndpointInfo {
+                    contract: #contract_name,
+                    endpoint: #ep_name,
+                    method: #method,
+                    path: #route,
+                },
+                request_options,
+                #request,
+            )
+            .await?
+        },
+        Backend::Blocking => quote! {
+            ::comfund::blocking::execute(#transport, request_options, #request)?
+        },
+    };
+
+    quote! {
+        let response = #response;
+        let head = ::comfund::Response::head(&response);
+
+        ::std::result::Result::Ok(head.with_body(#codec::#decode_fn(response)?))
+    }
+}
+
+/// Body of function, that returns unsent request of endpoint.
+fn prepare_body(state: ClientState, ep: &Endpoint, backend: Backend) -> impl ToTokens {
+    let ClientState {
+        root,
+        transport,
+        codec,
+    } = state;
+    let request = encode_call(&codec, root, ep);
+
+    match backend {
+        Backend::Async => quote! { #transport.prepare(#request) },
+        Backend::Blocking => quote! { ::comfund::blocking::prepare(#transport, #request) },
+    }
+}
+
+/// Request of endpoint, encoded by contract codec.
+fn encode_call(codec: &syn::Path, root: syn::Expr, ep: &Endpoint) -> impl ToTokens {
+    let encode_fn = encode_ident(ep);
+    let args = arg_names(ep);
+
+    quote! {
+        #codec::#encode_fn(#root, #(#args),*)?
+    }
+}
@@ -0,0 +1,163 @@
+//! Synchronous client on top of `reqwest::blocking`, generated with `reqwest-blocking` feature.
+
+use super::*;
+
+pub fn implement(contract: &Contract) -> impl ToTokens {
+    let client_ident = format_ident!("{}BlockingClient", &contract.id);
+    let api_ident = get_api_ident(&contract.id);
+    let attrs = contract.attrs.iter();
+
+    let client_struct = quote! {
+        #(#attrs)*
+        pub struct #client_ident {
+            root: ::std::borrow::Cow<'static, str>,
+            // Lazily initialized, so that clients can be created in const context
+            http_client: ::std::sync::OnceLock<::reqwest::blocking::Client>,
+        }
+    };
+
+    let api_trait = def_api_trait(contract, &api_ident);
+    let endpoints = contract
+        .endpoints
+        .iter()
+        .map(|ep| impl_endpoint(&api_ident, ep));
+
+    quote! {
+        #client_struct
+
+        impl #client_ident {
+            pub fn new(root: &impl ::std::string::ToString) -> Self {
+                Self {
+                    root: ::std::borrow::Cow::Owned(root.to_string()),
+                    http_client: ::std::sync::OnceLock::new(),
+                }
+            }
+
+            pub const fn new_const(root: &'static str) -> Self {
+                Self {
+                    root: ::std::borrow::Cow::Borrowed(root),
+                    http_client: ::std::sync::OnceLock::new(),
+                }
+            }
+
+            /// Send requests with specified HTTP client.
+            pub fn with_client(self, http_client: ::reqwest::blocking::Client) -> Self {
+                Self {
+                    http_client: ::std::sync::OnceLock::from(http_client),
+                    ..self
+                }
+            }
+
+            #(#endpoints)*
+        }
+
+        impl ::comfund::BlockingContractClient for #client_ident {
+            fn root(&self) -> &str {
+                &self.root
+            }
+
+            fn http_client(&self) -> &::reqwest::blocking::Client {
+                self.http_client.get_or_init(::reqwest::blocking::Client::new)
+            }
+        }
+
+        #api_trait
+
+        impl<C: ::comfund::BlockingContractClient> #api_ident for C {}
+    }
+}
+
+fn get_api_ident(contract_id: &syn::Ident) -> syn::Ident {
+    format_ident!("{}BlockingApi", contract_id)
+}
+
+/// Define trait with endpoints of contract, provided to blocking clients of this contract
+/// and of inheriting contracts.
+fn def_api_trait(contract: &Contract, api_ident: &syn::Ident) -> impl ToTokens {
+    let doc = format!(
+        " Blocking endpoints of `{}` contract, provided to blocking clients of this contract \
+        and of all contracts, that inherit it.",
+        contract.id
+    );
+
+    let supertraits = contract.supertraits.iter().map(|supertrait| {
+        supertrait.item_path(2, Some("reqwest"), &get_api_ident(supertrait.id()))
+    });
+
+    let endpoints = contract.endpoints.iter().map(|ep| {
+        let raw_with_name = Variant::RawWith.ident(ep);
+        let args = arg_names(ep).collect::<Vec<_>>();
+
+        let root: syn::Expr = parse_quote! { ::comfund::BlockingContractClient::root(self) };
+        let http_client: syn::Expr =
+            parse_quote! { ::comfund::BlockingContractClient::http_client(self) };
+
+        let prepare_sig = prepare_sig(ep, true, Backend::Blocking);
+        let prepare_doc = prepare_doc(ep, "Self::");
+        let state = ClientState::new(2, root, http_client);
+        let prepare_body = prepare_body(state.clone(), ep, Backend::Blocking);
+
+        let fns = endpoint_fns(
+            ep,
+            true,
+            None,
+            "Self::",
+            Backend::Blocking,
+            |variant| match variant {
+                Variant::RawWith => {
+                    impl_body(&contract.id, state.clone(), ep, Backend::Blocking).to_token_stream()
+                }
+                _ => variant.delegate(quote! { self.#raw_with_name }, &args, Backend::Blocking),
+            },
+        );
+
+        quote! {
+            #fns
+
+            #[doc = #prepare_doc]
+            #prepare_sig {
+                #prepare_body
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        pub trait #api_ident: ::comfund::BlockingContractClient #(+ #supertraits)* {
+            #(#endpoints)*
+        }
+    }
+}
+
+fn impl_endpoint(api_ident: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let args = arg_names(ep).collect::<Vec<_>>();
+
+    let fns = endpoint_fns(
+        ep,
+        true,
+        Some(quote! { pub }),
+        "Self::",
+        Backend::Blocking,
+        |variant| {
+            let name = variant.ident(ep);
+            let options = variant.has_options().then(|| quote! { request_options, });
+
+            quote! {
+                <Self as #api_ident>::#name(self, #options #(#args),*)
+            }
+        },
+    );
+
+    let prepare_name = prepare_ident(ep);
+    let prepare_sig = prepare_sig(ep, true, Backend::Blocking);
+    let prepare_doc = prepare_doc(ep, "Self::");
+
+    quote! {
+        #fns
+
+        #[doc = #prepare_doc]
+        pub #prepare_sig {
+            <Self as #api_ident>::#prepare_name(self, #(#args),*)
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Async client, generic over `comfund::ClientTransport`, generated with `reqwest` or `tower`
+//! feature. `reqwest` is only its default transport.
+
+use crate::contract::endpoint::Endpoint;
+use crate::Contract;
+use quote::{format_ident, quote, ToTokens};
+use syn::parse_quote;
+
+use super::{
+    arg_names, endpoint_fns, impl_body, prepare_body, prepare_doc, prepare_ident, prepare_sig,
+    Backend, ClientState, Variant,
+};
+
+pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
+    let client_impl = implement_client(contract);
+
+    // Other HTTP clients are plugged into generated client through `comfund::ClientTransport`
+    quote! {
+        #[cfg(any(feature = "reqwest", feature = "tower"))]
+        pub use transport::*;
+
+        #[cfg(any(feature = "reqwest", feature = "tower"))]
+        pub mod transport {
+            use super::*;
+            #client_impl
+        }
+    }
+}
+
+fn implement_client(contract: &Contract) -> impl ToTokens {
+    let client_ident = format_ident!("{}Client", &contract.id);
+    let api_ident = get_api_ident(&contract.id);
+    let attrs = &contract.attrs;
+
+    // `reqwest` transport is the default one, if enabled
+    let client_struct = quote! {
+        #[cfg(feature = "reqwest")]
+        #(#attrs)*
+        pub struct #client_ident<T = ::comfund::ReqwestTransport> {
+            root: ::std::borrow::Cow<'static, str>,
+            transport: T,
+        }
+
+        #[cfg(not(feature = "reqwest"))]
+        #(#attrs)*
+        pub struct #client_ident<T> {
+            root: ::std::borrow::Cow<'static, str>,
+            transport: T,
+        }
+    };
+
+    let api_trait = def_api_trait(contract, &api_ident);
+    let endpoints = contract
+        .endpoints
+        .iter()
+        .map(|ep| impl_endpoint(&api_ident, ep));
+    let prepare_fns = contract
+        .endpoints
+        .iter()
+        .map(|ep| impl_prepare(&api_ident, ep));
+
+    quote! {
+        #client_struct
+
+        #[cfg(feature = "reqwest")]
+        impl #client_ident {
+            pub fn new(root: &impl ::std::string::ToString) -> Self {
+                Self {
+                    root: ::std::borrow::Cow::Owned(root.to_string()),
+                    transport: ::comfund::ReqwestTransport::new(),
+                }
+            }
+
+            pub const fn new_const(root: &'static str) -> Self {
+                Self {
+                    root: ::std::borrow::Cow::Borrowed(root),
+                    transport: ::comfund::ReqwestTransport::new(),
+                }
+            }
+
+            /// Send requests with specified HTTP client.
+            pub fn with_client(self, http_client: ::reqwest::Client) -> Self {
+                Self {
+                    transport: self.transport.with_client(http_client),
+                    ..self
+                }
+            }
+
+            /// Run interceptor around every request. Interceptors are run in order of registration.
+            pub fn with_interceptor(self, interceptor: impl ::comfund::Interceptor) -> Self {
+                Self {
+                    transport: self.transport.with_interceptor(interceptor),
+                    ..self
+                }
+            }
+
+            /// Build client with configured HTTP client.
+            pub fn builder(root: &impl ::std::string::ToString) -> ::comfund::ClientBuilder<Self> {
+                ::comfund::ClientBuilder::new(root.to_string())
+            }
+
+            #(#prepare_fns)*
+        }
+
+        impl<T: ::comfund::ClientTransport> #client_ident<T> {
+            /// Create client, that sends requests with specified transport.
+            pub fn with_transport(root: &impl ::std::string::ToString, transport: T) -> Self {
+                Self {
+                    root: ::std::borrow::Cow::Owned(root.to_string()),
+                    transport,
+                }
+            }
+
+            #(#endpoints)*
+        }
+
+        #[cfg(all(feature = "tower", feature = "axum"))]
+        impl #client_ident<::comfund::TowerTransport<::axum::Router>> {
+            /// Create client, that serves requests in process with `router` (i.e. returned by
+            /// generated `route_[contract_name]` function), without opening a socket.
+            pub fn loopback_axum(router: ::axum::Router) -> Self {
+                Self::with_transport(&"http://loopback", ::comfund::TowerTransport::new(router))
+            }
+        }
+
+        #[cfg(feature = "actix-web")]
+        impl #client_ident<::comfund::ActixTransport> {
+            /// Create client, that serves requests in process with app, configured by `configure`
+            /// (i.e. generated `configure_[contract_name]` function), without opening a socket.
+            pub fn loopback_actix(
+                configure: impl FnOnce(&mut ::actix_web::web::ServiceConfig) + ::std::marker::Send + 'static,
+            ) -> Self {
+                Self::with_transport(&"http://loopback", ::comfund::ActixTransport::new(configure))
+            }
+        }
+
+        impl<T: ::comfund::ClientTransport> ::comfund::ContractClient for #client_ident<T> {
+            type Transport = T;
+
+            fn root(&self) -> &str {
+                &self.root
+            }
+
+            fn transport(&self) -> &T {
+                &self.transport
+            }
+
+            fn from_parts(root: ::std::borrow::Cow<'static, str>, transport: T) -> Self {
+                Self { root, transport }
+            }
+        }
+
+        #api_trait
+
+        // Implemented for every client, so that endpoints of contracts are available
+        // on clients of contracts, that inherit them at any depth (even several times).
+        impl<C: ::comfund::ContractClient> #api_ident for C {}
+    }
+}
+
+fn get_api_ident(contract_id: &syn::Ident) -> syn::Ident {
+    format_ident!("{}Api", contract_id)
+}
+
+/// Define trait with endpoints of contract, provided to clients of this contract
+/// and of inheriting contracts.
+fn def_api_trait(contract: &Contract, api_ident: &syn::Ident) -> impl ToTokens {
+    let doc = format!(
+        " Endpoints of `{}` contract, provided to clients of this contract \
+        and of all contracts, that inherit it.",
+        contract.id
+    );
+
+    let supertraits = contract.supertraits.iter().map(|supertrait| {
+        supertrait.item_path(1, Some("transport"), &get_api_ident(supertrait.id()))
+    });
+
+    let endpoints = contract.endpoints.iter().map(|ep| {
+        let raw_with_name = Variant::RawWith.ident(ep);
+        let args = arg_names(ep).collect::<Vec<_>>();
+
+        let root: syn::Expr = parse_quote! { ::comfund::ContractClient::root(self) };
+        let transport: syn::Expr = parse_quote! { ::comfund::ContractClient::transport(self) };
+
+        let prepare_sig = prepare_sig(ep, true, Backend::Async);
+        let prepare_doc = prepare_doc(ep, "Self::");
+        let state = ClientState::new(1, root, transport);
+        let prepare_body = prepare_body(state.clone(), ep, Backend::Async);
+
+        let fns = endpoint_fns(
+            ep,
+            true,
+            None,
+            "Self::",
+            Backend::Async,
+            |variant| match variant {
+                Variant::RawWith => {
+                    impl_body(&contract.id, state.clone(), ep, Backend::Async).to_token_stream()
+                }
+                _ => variant.delegate(quote! { self.#raw_with_name }, &args, Backend::Async),
+            },
+        );
+
+        quote! {
+            #fns
+
+            #[doc = #prepare_doc]
+            #[cfg(feature = "reqwest")]
+            #prepare_sig
+            where
+                Self: ::comfund::ContractClient<Transport = ::comfund::ReqwestTransport>,
+            {
+                #prepare_body
+            }
+        }
+    });
+
+    quote! {
+        #[doc = #doc]
+        #[allow(async_fn_in_trait)]
+        pub trait #api_ident: ::comfund::ContractClient #(+ #supertraits)* {
+            #(#endpoints)*
+        }
+    }
+}
+
+fn impl_endpoint(api_ident: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let args = arg_names(ep).collect::<Vec<_>>();
+
+    endpoint_fns(
+        ep,
+        true,
+        Some(quote! { pub }),
+        "Self::",
+        Backend::Async,
+        |variant| {
+            let name = variant.ident(ep);
+            let options = variant.has_options().then(|| quote! { request_options, });
+
+            quote! {
+                <Self as #api_ident>::#name(self, #options #(#args),*).await
+            }
+        },
+    )
+}
+
+/// Function, that returns unsent request of endpoint. Only available with `reqwest` transport.
+fn impl_prepare(api_ident: &syn::Ident, ep: &Endpoint) -> impl ToTokens {
+    let args = arg_names(ep).collect::<Vec<_>>();
+    let prepare_name = prepare_ident(ep);
+    let prepare_sig = prepare_sig(ep, true, Backend::Async);
+    let prepare_doc = prepare_doc(ep, "Self::");
+
+    quote! {
+        #[doc = #prepare_doc]
+        pub #prepare_sig {
+            <Self as #api_ident>::#prepare_name(self, #(#args),*)
+        }
+    }
+}
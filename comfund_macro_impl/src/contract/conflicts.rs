@@ -0,0 +1,97 @@
+//! Analysis of conflicting endpoints (see "Equivalence of endpoints" section of README).
+
+use comfund_paths::path_template::{PathTemplate, Segment};
+
+use crate::contract::endpoint::Endpoint;
+use crate::extensions::*;
+
+/// Check every pair of endpoints for conflicts, reporting errors at both of conflicting endpoints.
+pub fn validate_conflicts(eps: &[Endpoint]) -> syn::Result<()> {
+    let paths = eps.iter().map(|ep| ep.meta.path()).collect::<Vec<_>>();
+    // Invalid paths are reported by endpoints themselves
+    let templates = paths
+        .iter()
+        .map(|path| PathTemplate::new(path).ok())
+        .collect::<Vec<_>>();
+
+    let mut errors = None;
+
+    for (i, (a, a_template)) in eps.iter().zip(&templates).enumerate() {
+        for (b, b_template) in eps.iter().zip(&templates).skip(i + 1) {
+            let (Some(a_template), Some(b_template)) = (a_template, b_template) else {
+                continue;
+            };
+
+            if let Some(message) = find_conflict(a, a_template, b, b_template) {
+                errors.combine(syn::Error::new_spanned(&a.meta_attr, &message));
+                errors.combine(syn::Error::new_spanned(&b.meta_attr, &message));
+            }
+        }
+    }
+
+    if let Some(err) = errors {
+        Err(err)
+    } else {
+        Ok(())
+    }
+}
+
+fn find_conflict(
+    a: &Endpoint,
+    a_template: &PathTemplate,
+    b: &Endpoint,
+    b_template: &PathTemplate,
+) -> Option<String> {
+    let (a_id, b_id) = (&a.id, &b.id);
+    let path = a.meta.path();
+    let method = a.meta.method();
+
+    // 1. Endpoints are mounted on the same url.
+    if !is_same_url(a_template, b_template) {
+        return None;
+    }
+
+    // 2. Endpoints have the same HTTP method.
+    if method != b.meta.method() {
+        return if a_template == b_template {
+            None
+        } else {
+            Some(format!(
+                "Endpoints `{a_id}` and `{b_id}` are mounted on the same url `{path}`, \
+                but name its captures differently."
+            ))
+        };
+    }
+
+    // 3. Endpoints have the same accepted `content-type`.
+    //
+    // Rule 4 always holds for endpoints, mounted on the same url, as they
+    // have the same number of path params and no relative priority rules
+    // can be set yet.
+    if a.accepted_content_type() == b.accepted_content_type() {
+        Some(format!(
+            "Conflicting endpoints `{a_id}` and `{b_id}`: both are mounted on `{method} {path}` \
+            and accept the same content type."
+        ))
+    } else {
+        Some(format!(
+            "Endpoints `{a_id}` and `{b_id}` are both mounted on `{method} {path}`. \
+            Dispatching requests by accepted content type is not supported by server backends yet."
+        ))
+    }
+}
+
+/// Check, if templates match the same urls, regardless of capture names.
+fn is_same_url(a: &PathTemplate, b: &PathTemplate) -> bool {
+    let same_segments = a.segments().len() == b.segments().len()
+        && a.segments()
+            .iter()
+            .zip(b.segments())
+            .all(|pair| match pair {
+                (Segment::Static(a), Segment::Static(b)) => a == b,
+                (Segment::Capture(_), Segment::Capture(_)) => true,
+                _ => false,
+            });
+
+    same_segments && a.wildcard().is_some() == b.wildcard().is_some()
+}
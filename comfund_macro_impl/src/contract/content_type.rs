@@ -1,8 +1,9 @@
-#[derive(Debug, Clone, PartialEq, Eq, deluxe::ParseMetaItem)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, deluxe::ParseMetaItem)]
 pub enum ContentType {
     #[deluxe(rename = application_json)]
     ApplicationJson,
     #[deluxe(rename = text_plain)]
+    #[default]
     TextPlain,
 }
 
@@ -18,12 +19,6 @@ impl std::str::FromStr for ContentType {
     }
 }
 
-impl Default for ContentType {
-    fn default() -> Self {
-        Self::TextPlain
-    }
-}
-
 pub struct ContentTypeError;
 
 impl std::fmt::Display for ContentTypeError {
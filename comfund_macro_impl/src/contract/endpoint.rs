@@ -17,14 +17,24 @@ pub struct Endpoint {
     pub id: syn::Ident,
     /// Endpoint metadata
     pub meta: EndpointMeta,
+    /// Full path of endpoint, including contract prefix
+    pub route: syn::LitStr,
+    /// Original `#[endpoint]` attribute (used for diagnostics)
+    pub meta_attr: syn::Attribute,
     /// Params passed in path part of endpoint request
     pub path_inputs: Option<Inputs>,
     /// Params passed in query part of endpoint request
     pub query_inputs: Option<Inputs>,
+    /// Params passed in headers of endpoint request
+    pub header_inputs: Option<Inputs>,
+    /// Params passed in cookies of endpoint request
+    pub cookie_inputs: Option<Inputs>,
     /// Body param of endpoint request
     pub body_param: Option<Param>,
-    /// Expected result of endpoint
+    /// Expected result of endpoint (`Ok` type for endpoints, returning `Result`)
     pub ret: syn::Type,
+    /// Error type for endpoints, returning `Result<T, E>`
+    pub err: Option<syn::Type>,
     /// Forwarded fn attributes
     pub attrs: Vec<syn::Attribute>,
 }
@@ -33,10 +43,15 @@ impl Endpoint {
     pub fn parse(
         fn_item: syn::TraitItemFn,
         endpoint_defaults: &EndpointOptions,
+        contract_options: &ContractOptions,
     ) -> Result<Self, syn::Error> {
         let id = fn_item.sig.ident.clone();
 
         let mut attrs = fn_item.attrs;
+        let meta_attr = attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("endpoint"))
+            .cloned();
         let meta = deluxe::extract_attributes::<_, EndpointMeta>(&mut attrs);
 
         let sig_validation = validate_signature(&fn_item.sig);
@@ -46,23 +61,52 @@ impl Endpoint {
 
         let (_, mut meta, params, ret) = combine_results!(sig_validation, meta, params, ret)?;
 
-        let (path_inputs, query_inputs, body_param) = gen_inputs(&id, params)?;
+        // Attribute is present, as metadata was extracted successfully
+        let meta_attr = meta_attr.unwrap();
+        let inputs = gen_inputs(&id, params)?;
+        let (ret, err) = split_result(ret, contract_options.error.as_ref());
 
         meta.2 = meta.2.merge(endpoint_defaults);
+        let route = prefixed_path(contract_options.prefix.as_ref(), meta.path_lit());
 
         Ok(Self {
             id,
             meta,
-            path_inputs,
-            query_inputs,
-            body_param,
+            route,
+            meta_attr,
+            path_inputs: inputs.path,
+            query_inputs: inputs.query,
+            header_inputs: inputs.header,
+            cookie_inputs: inputs.cookie,
+            body_param: inputs.body,
             ret,
+            err,
             attrs,
         })
     }
 
     pub fn validate(&self) -> Result<(), syn::Error> {
-        validate_path(self.meta.path_lit())
+        let status = validate_status(self.meta.options().status.as_ref());
+
+        let path_lit = self.meta.path_lit();
+        let path = path_lit.value();
+        let path_params = self
+            .path_inputs
+            .as_ref()
+            .map_or(&[][..], |inputs| &inputs.params);
+        let path = validate_path(path_lit, &path)
+            .and_then(|template| validate_path_params(path_lit, &template, path_params));
+
+        combine_results!(status, path)?;
+
+        Ok(())
+    }
+
+    /// `content-type` of request body, accepted by endpoint.
+    pub fn accepted_content_type(&self) -> Option<&'static str> {
+        self.body_param
+            .as_ref()
+            .and_then(|param| param.meta.transport().content_type())
     }
 }
 
@@ -109,14 +153,50 @@ pub struct EndpointOptions {
     /// Content type for endpoint
     #[deluxe(with = content_type_optional)]
     pub content_type: Option<ContentType>,
+    /// Status code of successful responses
+    pub status: Option<syn::LitInt>,
 }
 
 impl EndpointOptions {
     pub fn merge(mut self, defaults: &Self) -> Self {
         self.content_type = self.content_type.or(defaults.content_type.clone());
-        
+        self.status = self.status.or(defaults.status.clone());
+
         self
     }
+
+    /// Declared status code of successful responses (validated in endpoint).
+    pub fn status(&self) -> Option<u16> {
+        self.status
+            .as_ref()
+            .and_then(|status| status.base10_parse().ok())
+    }
+}
+
+/// Prepend contract prefix to endpoint path.
+fn prefixed_path(prefix: Option<&syn::LitStr>, path_lit: &syn::LitStr) -> syn::LitStr {
+    let Some(prefix) = prefix else {
+        return path_lit.clone();
+    };
+
+    let prefix = prefix.value();
+    let prefix = prefix.trim_end_matches('/');
+    let path = path_lit.value();
+    let path = path.trim_start_matches('/');
+
+    let route = if path.is_empty() {
+        prefix.to_owned()
+    } else {
+        format!("{prefix}/{path}")
+    };
+    // Prefix "/" and path "/" are both trimmed away
+    let route = if route.is_empty() {
+        "/".to_owned()
+    } else {
+        route
+    };
+
+    syn::LitStr::new(&route, path_lit.span())
 }
 
 fn get_returned_type(ty: &syn::ReturnType) -> syn::Result<syn::Type> {
@@ -139,73 +219,114 @@ fn get_returned_type(ty: &syn::ReturnType) -> syn::Result<syn::Type> {
     }
 }
 
-fn gen_inputs(
-    ep_name: &syn::Ident,
-    params: Vec<Param>,
-) -> syn::Result<(Option<Inputs>, Option<Inputs>, Option<Param>)> {
-    let mut errors = None;
-    let mut params = params.into_iter().peekable();
-
-    // Path params
-
-    let mut path_params = vec![];
+/// Split `Result<T, E>` into `Ok` and `Err` types. Other types are returned as is.
+///
+/// `Result<T>` is split with contract-wide default error type, if one is specified.
+fn split_result(ty: syn::Type, default_err: Option<&syn::Type>) -> (syn::Type, Option<syn::Type>) {
+    let syn::Type::Path(syn::TypePath { qself: None, path }) = &ty else {
+        return (ty, None);
+    };
+
+    let last = path.segments.last().unwrap();
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return (ty, None);
+    };
+
+    let types = args
+        .args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
 
-    while let Some(p) = params.peek() {
-        if p.meta.0 != Transport::Path {
-            break;
+    match types.as_slice() {
+        [ok, err] if last.ident == "Result" && args.args.len() == 2 => {
+            ((*ok).clone(), Some((*err).clone()))
         }
-
-        path_params.push(params.next().unwrap());
+        [ok] if last.ident == "Result" && args.args.len() == 1 && default_err.is_some() => {
+            ((*ok).clone(), default_err.cloned())
+        }
+        _ => (ty, None),
     }
+}
 
-    let path_inputs = inputs::from_params(ep_name, path_params, "_path_inputs");
-    // Query params
+fn gen_inputs(ep_name: &syn::Ident, params: Vec<Param>) -> syn::Result<EndpointInputs> {
+    let mut errors = None;
 
+    let mut path_params = vec![];
     let mut query_params = vec![];
-
-    while let Some(p) = params.peek() {
-        match p.meta.0 {
-            Transport::Path => {
-                combine_err!(
-                    errors,
-                    &p.name,
-                    "Path params should be specified before query params."
-                );
-                params.next().unwrap();
-            }
-            Transport::Query => query_params.push(params.next().unwrap()),
-            _ => break,
+    let mut header_params = vec![];
+    let mut cookie_params = vec![];
+    let mut body_param: Option<Param> = None;
+
+    let mut last_kind: Option<&'static str> = None;
+    let mut last_order = 0;
+
+    for param in params {
+        let transport = param.meta.transport();
+
+        if body_param.is_some() {
+            combine_err!(
+                errors,
+                &param.name,
+                "Unexpected param. At most one body param is supported and no other params can be passed after body param."
+            );
+            continue;
         }
-    }
 
-    let query_inputs = inputs::from_params(ep_name, query_params, "_query_inputs");
-
-    // Body param
-
-    let body_param = params.next().and_then(|param| match param.meta.0 {
-        Transport::Path | Transport::Query => {
-            combine_err!(errors, &param.name, "Unexpected transport type");
-            None
+        if transport.order() < last_order {
+            let mut kind = transport.kind().to_owned();
+            kind[..1].make_ascii_uppercase();
+
+            combine_err!(
+                errors,
+                &param.name,
+                format!(
+                    "{kind} params should be specified before {} params.",
+                    last_kind.unwrap_or_default()
+                )
+            );
+            continue;
         }
-        _ => Some(param),
-    });
 
-    // Leftover incorrect params
+        last_kind = Some(transport.kind());
+        last_order = transport.order();
 
-    for leftover in params {
-        combine_err!(
-            errors,
-            leftover.name,
-            "Unexpected param. At most one body param is supported and no other params can be passed after body param.")
+        match transport {
+            Transport::Path => path_params.push(param),
+            Transport::Query => query_params.push(param),
+            Transport::Header(_) => header_params.push(param),
+            Transport::Cookie(_) => cookie_params.push(param),
+            _ => body_param = Some(param),
+        }
     }
 
+    let inputs = EndpointInputs {
+        path: inputs::from_params(ep_name, path_params, "_path_inputs"),
+        query: inputs::from_params(ep_name, query_params, "_query_inputs"),
+        header: inputs::struct_from_params(ep_name, header_params, "_header_inputs"),
+        cookie: inputs::struct_from_params(ep_name, cookie_params, "_cookie_inputs"),
+        body: body_param,
+    };
+
     if let Some(err) = errors {
         Err(err)
     } else {
-        Ok((path_inputs, query_inputs, body_param))
+        Ok(inputs)
     }
 }
 
+/// Params of endpoint, grouped by transport.
+struct EndpointInputs {
+    path: Option<Inputs>,
+    query: Option<Inputs>,
+    header: Option<Inputs>,
+    cookie: Option<Inputs>,
+    body: Option<Param>,
+}
+
 fn validate_signature(sig: &syn::Signature) -> Result<(), syn::Error> {
     let mut errors = None;
 
@@ -217,12 +338,8 @@ fn validate_signature(sig: &syn::Signature) -> Result<(), syn::Error> {
         );
     }
 
-    if let Some(ref asynncness) = sig.asyncness {
-        errors.combine(syn::Error::new_spanned(
-            asynncness,
-            "Asyncness of endpoints is not controlled by contracts.",
-        ));
-    }
+    // `async fn` is accepted as an equivalent spelling of endpoint, as asyncness
+    // of generated code is not controlled by contracts.
 
     if let Some(ref unsafety) = sig.unsafety {
         errors.combine(syn::Error::new_spanned(
@@ -259,10 +376,87 @@ fn validate_signature(sig: &syn::Signature) -> Result<(), syn::Error> {
     }
 }
 
-fn validate_path(path: &syn::LitStr) -> syn::Result<()> {
-    let path_str = path.value();
-    comfund_paths::PathTemplate::new(&path_str)
-        .map_err(|err| syn::Error::new_spanned(path, format!("invalid path: {err}")))?;
+pub fn validate_path<'p>(path_lit: &syn::LitStr, path: &'p str) -> syn::Result<PathTemplate<'p>> {
+    PathTemplate::new(path).map_err(|err| {
+        let span = lit_str_subspan(path_lit, err.span()).unwrap_or_else(|| path_lit.span());
+
+        syn::Error::new(span, format!("invalid path: {err}"))
+    })
+}
+
+fn validate_status(status: Option<&syn::LitInt>) -> syn::Result<()> {
+    let Some(lit) = status else {
+        return Ok(());
+    };
+
+    if (200..300).contains(&lit.base10_parse::<u16>()?) {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(
+            lit,
+            "Status of successful responses should be a 2xx code.",
+        ))
+    }
+}
+
+/// Check, that every capture of path template has matching path param and vice versa.
+fn validate_path_params(
+    path_lit: &syn::LitStr,
+    template: &PathTemplate,
+    params: &[Param],
+) -> syn::Result<()> {
+    let mut errors = None;
 
-    Ok(())
+    let captures = template
+        .idents()
+        .iter()
+        .copied()
+        .chain(template.wildcard())
+        .collect::<Vec<_>>();
+
+    for (i, capture) in captures.iter().enumerate() {
+        if captures[..i].contains(capture) {
+            combine_err!(
+                errors,
+                path_lit,
+                format!("Capture `{capture}` is repeated in endpoint path.")
+            );
+        }
+    }
+
+    // Names of captures, that are passed through flattened param, are not known
+    let (flattened, named): (Vec<_>, Vec<_>) = params
+        .iter()
+        .partition(|param| param.meta.options().flatten.is_set());
+
+    for param in named.iter() {
+        if !captures.iter().any(|capture| param.name == capture) {
+            combine_err!(
+                errors,
+                &param.name,
+                format!(
+                    "Path param `{}` has no matching capture in endpoint path.",
+                    param.name
+                )
+            );
+        }
+    }
+
+    if flattened.is_empty() {
+        for capture in &captures {
+            if !named.iter().any(|param| param.name == capture) {
+                combine_err!(
+                    errors,
+                    path_lit,
+                    format!("Capture `{capture}` has no matching `#[param(path)]` argument.")
+                );
+            }
+        }
+    }
+
+    if let Some(err) = errors {
+        Err(err)
+    } else {
+        Ok(())
+    }
 }
@@ -77,7 +77,14 @@ pub fn from_params(ep_name: &syn::Ident, params: Vec<Param>, suffix: &str) -> Op
             let flatten = if param.meta.options().flatten.is_set() {
                 Some(quote! {
                     #[cfg_attr(
-                        any(feature = "reqwest", feature = "actix-web", feature = "axum"),
+                        any(
+                            feature = "client",
+                            feature = "reqwest",
+                            feature = "reqwest-blocking",
+                            feature = "tower",
+                            feature = "actix-web",
+                            feature = "axum"
+                        ),
                         serde(flatten)
                     )]
                 })
@@ -93,7 +100,12 @@ pub fn from_params(ep_name: &syn::Ident, params: Vec<Param>, suffix: &str) -> Op
 
         let definition = quote! {
             #[cfg_attr(
-                any(feature = "reqwest"),
+                any(
+                    feature = "client",
+                    feature = "reqwest",
+                    feature = "reqwest-blocking",
+                    feature = "tower"
+                ),
                 derive(::serde::Serialize)
             )]
             #[cfg_attr(
@@ -114,6 +126,43 @@ pub fn from_params(ep_name: &syn::Ident, params: Vec<Param>, suffix: &str) -> Op
     }
 }
 
+/// Generate plain struct, that holds all of the params, regardless of their count.
+///
+/// Used for transports, that are extracted by generated code rather than through `serde`.
+pub fn struct_from_params(
+    ep_name: &syn::Ident,
+    params: Vec<Param>,
+    suffix: &str,
+) -> Option<Inputs> {
+    if params.is_empty() {
+        return None;
+    }
+
+    let ty = gen_type(ep_name, suffix);
+
+    let fields = params.iter().map(|param| {
+        let name = &param.name;
+        let ty = &param.ty;
+
+        quote! {
+            pub #name: #ty
+        }
+    });
+
+    let definition = quote! {
+        pub struct #ty {
+            #(#fields),*
+        }
+    };
+
+    Some(Inputs {
+        id: None,
+        ty,
+        params,
+        definition: Some(definition),
+    })
+}
+
 fn gen_type(ep_name: &syn::Ident, suffix: &str) -> syn::Type {
     let mut ep_str = ep_name.to_string();
     ep_str.push_str(suffix);
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy, deluxe::ParseMetaItem)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, deluxe::ParseMetaItem)]
 pub enum Method {
     #[deluxe(rename = get)]
     Get,
@@ -27,6 +27,18 @@ impl std::str::FromStr for Method {
     }
 }
 
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Patch => "PATCH",
+            Self::Delete => "DELETE",
+            Self::Put => "PUT",
+        })
+    }
+}
+
 pub struct ParseMethodError;
 
 impl std::fmt::Display for ParseMethodError {
@@ -1,14 +1,17 @@
+pub mod conflicts;
 pub mod content_type;
 pub mod endpoint;
 pub mod inputs;
 pub mod method;
 pub mod param;
 pub mod query;
+pub mod supertrait;
 pub mod transport;
 
 use quote::quote;
 
 use endpoint::Endpoint;
+use supertrait::Supertrait;
 
 use crate::{contract::content_type::ContentType, extensions::*};
 
@@ -27,6 +30,18 @@ pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
                 stream.extend(def.clone());
             }
         }
+
+        if let Some(input) = &ep.header_inputs {
+            if let Some(def) = &input.definition {
+                stream.extend(def.clone());
+            }
+        }
+
+        if let Some(input) = &ep.cookie_inputs {
+            if let Some(def) = &input.definition {
+                stream.extend(def.clone());
+            }
+        }
     }
 
     stream
@@ -36,28 +51,39 @@ pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
 pub struct Contract {
     pub id: syn::Ident,
     pub endpoints: Vec<Endpoint>,
+    /// Other contracts, inherited by this contract
+    pub supertraits: Vec<Supertrait>,
     pub meta: ServiceMeta,
     pub attrs: Vec<syn::Attribute>,
 }
 
 #[derive(Debug, Clone, deluxe::ParseMetaItem)]
-pub struct ServiceMeta(
-    #[deluxe(flatten)] pub endpoint::EndpointOptions,
-    #[deluxe(flatten)] pub ContractOptions
-);
+pub struct ServiceMeta {
+    /// Default options of contract endpoints
+    #[deluxe(flatten)]
+    pub endpoint_defaults: endpoint::EndpointOptions,
+    /// Contract-wide options
+    #[deluxe(flatten)]
+    pub options: ContractOptions,
+}
 
 impl ServiceMeta {
     pub fn endpoint_defaults(&self) -> &endpoint::EndpointOptions {
-        &self.0
-    } 
+        &self.endpoint_defaults
+    }
 
     pub fn options(&self) -> &ContractOptions {
-        &self.1
+        &self.options
     }
 }
 
-#[derive(Debug, Clone, deluxe::ParseMetaItem)]
+#[derive(Debug, Clone, Default, deluxe::ParseMetaItem)]
+#[deluxe(default)]
 pub struct ContractOptions {
+    /// Path prefix, prepended to paths of all endpoints of contract
+    pub prefix: Option<syn::LitStr>,
+    /// Default error type of endpoints, returning `Result<T>`
+    pub error: Option<syn::Type>,
 }
 
 impl Contract {
@@ -68,10 +94,15 @@ impl Contract {
 
         let id = item_trait.ident;
         let attrs = item_trait.attrs;
+        let supertraits = item_trait
+            .supertraits
+            .into_iter()
+            .map(Supertrait::parse)
+            .partition_syn_err(&mut errors);
         let fn_items = get_fn_items(item_trait.items, &mut errors);
         let endpoints = fn_items
             .into_iter()
-            .map(|item| Endpoint::parse(item, meta.endpoint_defaults()))
+            .map(|item| Endpoint::parse(item, meta.endpoint_defaults(), meta.options()))
             .partition_syn_err(&mut errors);
 
         if let Some(err) = errors {
@@ -80,6 +111,7 @@ impl Contract {
             Ok(Self {
                 id,
                 endpoints,
+                supertraits,
                 meta,
                 attrs,
             })
@@ -87,10 +119,13 @@ impl Contract {
     }
 
     pub fn validate(&self) -> syn::Result<()> {
+        let p = validate_prefix(self.meta.options());
         let u = validate_endpoints_uniqueness(&self.endpoints);
+        let g = validate_generated_idents(&self.endpoints);
         let c = validate_endpoints_correctness(&self.endpoints);
+        let e = conflicts::validate_conflicts(&self.endpoints);
 
-        combine_results!(u, c)?;
+        combine_results!(p, u, g, c, e)?;
 
         Ok(())
     }
@@ -113,6 +148,31 @@ fn get_fn_items(
         .partition_syn_err(errors)
 }
 
+fn validate_prefix(options: &ContractOptions) -> syn::Result<()> {
+    let Some(prefix_lit) = &options.prefix else {
+        return Ok(());
+    };
+
+    let prefix = prefix_lit.value();
+    if !prefix.starts_with('/') {
+        return Err(syn::Error::new_spanned(
+            prefix_lit,
+            "Contract prefix should start with `/`.",
+        ));
+    }
+
+    let template = endpoint::validate_path(prefix_lit, &prefix)?;
+
+    if template.param_count() > 0 {
+        Err(syn::Error::new_spanned(
+            prefix_lit,
+            "Contract prefix can't contain captures.",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn validate_endpoints_correctness(eps: &[Endpoint]) -> syn::Result<()> {
     let errors = eps
         .iter()
@@ -150,3 +210,66 @@ fn validate_endpoints_uniqueness(eps: &[Endpoint]) -> syn::Result<()> {
         Ok(())
     }
 }
+
+/// Functions of generated clients, that aren't derived from names of endpoints
+/// (including functions of `ContractClient` and `BlockingContractClient` traits).
+const RESERVED_IDENTS: [&str; 12] = [
+    "new",
+    "new_const",
+    "with_client",
+    "with_interceptor",
+    "builder",
+    "with_transport",
+    "loopback_axum",
+    "loopback_actix",
+    "root",
+    "transport",
+    "from_parts",
+    "http_client",
+];
+
+/// Check, that no endpoint is named as function, generated for another endpoint
+/// (i.e. `[endpoint]_with`, `[endpoint]_raw`, `[endpoint]_raw_with`, `prepare_[endpoint]`,
+/// `encode_[endpoint]` and `decode_[endpoint]`), or as any of [`RESERVED_IDENTS`].
+fn validate_generated_idents(eps: &[Endpoint]) -> syn::Result<()> {
+    let generated = eps
+        .iter()
+        .flat_map(|ep| {
+            let name = ep.id.to_string();
+
+            [
+                format!("{name}_with"),
+                format!("{name}_raw"),
+                format!("{name}_raw_with"),
+                format!("prepare_{name}"),
+                format!("encode_{name}"),
+                format!("decode_{name}"),
+            ]
+            .map(|generated| (generated, &ep.id))
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+
+    let mut errors = None;
+
+    for id in eps.iter().map(|ep| &ep.id) {
+        if RESERVED_IDENTS.contains(&id.to_string().as_str()) {
+            combine_err!(
+                errors,
+                id,
+                format!("Endpoint ident `{id}` is reserved for function of generated client")
+            )
+        } else if let Some(origin) = generated.get(&id.to_string()) {
+            combine_err!(
+                errors,
+                id,
+                format!("Endpoint ident `{id}` clashes with function, generated for endpoint `{origin}`")
+            )
+        }
+    }
+
+    if let Some(err) = errors {
+        Err(err)
+    } else {
+        Ok(())
+    }
+}
@@ -67,14 +67,11 @@ impl Param {
 
 #[derive(Debug, Clone, PartialEq, Eq, deluxe::ExtractAttributes)]
 #[deluxe(attributes(param))]
-pub struct ParamMeta(
-    #[deluxe(with = crate::utils::parse_ident)] pub Transport,
-    #[deluxe(flatten)] pub ParamOptions,
-);
+pub struct ParamMeta(pub Transport, #[deluxe(flatten)] pub ParamOptions);
 
 impl ParamMeta {
-    pub fn transport(&self) -> Transport {
-        self.0
+    pub fn transport(&self) -> &Transport {
+        &self.0
     }
 
     pub fn options(&self) -> &ParamOptions {
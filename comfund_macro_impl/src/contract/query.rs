@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 
 pub enum Query {
-    Flat(syn::Type),
+    Flat(Box<syn::Type>),
     Generated(Generated),
 }
 
@@ -0,0 +1,77 @@
+//! Contracts, inherited by contract through supertraits.
+
+use quote::format_ident;
+
+/// Other contract, specified as a supertrait of contract.
+#[derive(Debug, Clone)]
+pub struct Supertrait {
+    /// Path to contract, as written in supertrait list
+    pub path: syn::Path,
+}
+
+impl Supertrait {
+    pub fn parse(bound: syn::TypeParamBound) -> syn::Result<Self> {
+        let message = "Only other contracts (specified by path without generic arguments) \
+            are supported as supertraits.";
+
+        let syn::TypeParamBound::Trait(bound) = bound else {
+            return Err(syn::Error::new_spanned(bound, message));
+        };
+
+        let is_plain_path = bound.paren_token.is_none()
+            && bound.lifetimes.is_none()
+            && matches!(bound.modifier, syn::TraitBoundModifier::None)
+            && bound
+                .path
+                .segments
+                .iter()
+                .all(|seg| seg.arguments.is_none());
+
+        if is_plain_path {
+            Ok(Self { path: bound.path })
+        } else {
+            Err(syn::Error::new_spanned(bound, message))
+        }
+    }
+
+    /// Ident of inherited contract.
+    pub fn id(&self) -> &syn::Ident {
+        &self.path.segments.last().unwrap().ident
+    }
+
+    /// Path to item, generated for inherited contract.
+    ///
+    /// Path is resolved from module, nested `depth` levels deep into module of contract
+    /// definition. Item is looked up in `module`, generated for inherited contract
+    /// (i.e. `axum`), or in the module of inherited contract itself, if `module` is `None`.
+    pub fn item_path(&self, depth: usize, module: Option<&str>, item: &syn::Ident) -> syn::Path {
+        let mut segments = self
+            .path
+            .segments
+            .iter()
+            .map(|seg| seg.ident.clone())
+            .collect::<Vec<_>>();
+        // Contract ident
+        segments.pop();
+
+        let is_absolute =
+            self.path.leading_colon.is_some() || segments.first().is_some_and(|seg| seg == "crate");
+
+        if !is_absolute {
+            if segments.first().is_some_and(|seg| seg == "self") {
+                segments.remove(0);
+            }
+
+            let supers = (0..depth).map(|_| format_ident!("super"));
+            segments.splice(0..0, supers);
+        }
+
+        segments.extend(module.map(|module| format_ident!("{module}")));
+        segments.push(item.clone());
+
+        syn::Path {
+            leading_colon: self.path.leading_colon,
+            segments: segments.into_iter().map(syn::PathSegment::from).collect(),
+        }
+    }
+}
@@ -1,19 +1,88 @@
-use std::{borrow::Borrow, str::FromStr};
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, deluxe::ParseMetaItem)]
+use deluxe_core::{ParseMetaItem, ParseMode};
+use syn::parse::ParseStream;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Transport {
-    #[deluxe(rename = path)]
     Path,
-    #[deluxe(rename = query)]
     Query,
-    #[deluxe(rename = body)]
+    /// Request header with specified name.
+    Header(syn::LitStr),
+    /// Request cookie with specified name.
+    Cookie(syn::LitStr),
     Body,
-    #[deluxe(rename = json)]
     Json,
-    #[deluxe(rename = multipart)]
+    /// `application/x-www-form-urlencoded` body.
+    Form,
     Multipart,
 }
 
+impl Transport {
+    /// Relative position of params with this transport in endpoint signature.
+    pub fn order(&self) -> usize {
+        match self {
+            Self::Path => 0,
+            Self::Query => 1,
+            Self::Header(_) => 2,
+            Self::Cookie(_) => 3,
+            Self::Body | Self::Json | Self::Form | Self::Multipart => 4,
+        }
+    }
+
+    /// Check, if transport places param into request body.
+    pub fn is_body(&self) -> bool {
+        self.order() == 4
+    }
+
+    /// `content-type` of request body for body transports.
+    pub fn content_type(&self) -> Option<&'static str> {
+        match self {
+            Self::Body => Some("text/plain"),
+            Self::Json => Some("application/json"),
+            Self::Form => Some("application/x-www-form-urlencoded"),
+            Self::Multipart => Some("multipart/form-data"),
+            _ => None,
+        }
+    }
+
+    /// Human readable name of transport kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Path => "path",
+            Self::Query => "query",
+            Self::Header(_) => "header",
+            Self::Cookie(_) => "cookie",
+            Self::Body | Self::Json | Self::Form | Self::Multipart => "body",
+        }
+    }
+}
+
+impl ParseMetaItem for Transport {
+    fn parse_meta_item(input: ParseStream, _mode: ParseMode) -> deluxe_core::Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+
+        match ident.to_string().as_str() {
+            "header" => {
+                input.parse::<syn::Token![=]>()?;
+                let name = input.parse::<syn::LitStr>()?;
+                validate_token(&name, "Invalid header name.")?;
+
+                Ok(Self::Header(name))
+            }
+            "cookie" => {
+                input.parse::<syn::Token![=]>()?;
+                let name = input.parse::<syn::LitStr>()?;
+                validate_token(&name, "Invalid cookie name.")?;
+
+                Ok(Self::Cookie(name))
+            }
+            other => Self::from_str(other)
+                .map_err(|err| syn::Error::new_spanned(&ident, err.to_string())),
+        }
+    }
+}
+
 impl FromStr for Transport {
     type Err = ParseTransportError;
 
@@ -23,6 +92,7 @@ impl FromStr for Transport {
             "query" => Ok(Self::Query),
             "body" => Ok(Self::Body),
             "json" => Ok(Self::Json),
+            "form" => Ok(Self::Form),
             "multipart" => Ok(Self::Multipart),
             _ => Err(ParseTransportError),
         }
@@ -36,3 +106,16 @@ impl std::fmt::Display for ParseTransportError {
         f.write_str("Unknown/unsupported transport type.")
     }
 }
+
+/// Both header and cookie names should be a `token` from RFC 9110.
+fn validate_token(name: &syn::LitStr, message: &str) -> syn::Result<()> {
+    let value = name.value();
+    // `tchar` set from RFC 9110
+    let is_token_char = |ch: char| ch.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(ch);
+
+    if !value.is_empty() && value.chars().all(is_token_char) {
+        Ok(())
+    } else {
+        Err(syn::Error::new_spanned(name, message))
+    }
+}
@@ -0,0 +1,88 @@
+use quote::quote;
+
+use crate::extensions::*;
+
+/// Implement `comfund::EndpointError` with status codes, declared by `#[status(code)]` attributes.
+///
+/// Attribute of enum is used as a default status of its variants.
+pub fn implement(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let id = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut errors = None;
+
+    let default_status = match parse_status(&input.attrs) {
+        Ok(status) => status,
+        Err(err) => {
+            errors.combine(err);
+            None
+        }
+    };
+
+    let status_expr = match &input.data {
+        syn::Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let variant_id = &variant.ident;
+                    let status = parse_status(&variant.attrs)?
+                        .or(default_status)
+                        .unwrap_or(500);
+
+                    Ok(quote! {
+                        Self::#variant_id { .. } => #status
+                    })
+                })
+                .partition_syn_err(&mut errors);
+
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+        syn::Data::Struct(_) => {
+            let status = default_status.unwrap_or(500);
+
+            quote! { #status }
+        }
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                id,
+                "`EndpointError` can only be derived for structs and enums.",
+            ))
+        }
+    };
+
+    if let Some(err) = errors {
+        return Err(err);
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::comfund::EndpointError for #id #ty_generics #where_clause {
+            fn status(&self) -> u16 {
+                #status_expr
+            }
+        }
+    })
+}
+
+/// Parse status code from `#[status(code)]` attribute (if present).
+fn parse_status(attrs: &[syn::Attribute]) -> syn::Result<Option<u16>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("status")) else {
+        return Ok(None);
+    };
+
+    let lit = attr.parse_args::<syn::LitInt>()?;
+    let status = lit.base10_parse::<u16>()?;
+
+    if (400..600).contains(&status) {
+        Ok(Some(status))
+    } else {
+        Err(syn::Error::new_spanned(
+            lit,
+            "Status of endpoint error should be a 4xx or 5xx code.",
+        ))
+    }
+}
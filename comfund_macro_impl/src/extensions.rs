@@ -94,3 +94,25 @@ impl<T, I: Iterator<Item = syn::Result<T>>> CollectSynResults<T> for I {
         }
     }
 }
+
+/// Get span of a part of string literal value, specified by byte offsets.
+///
+/// Returns `None`, if compiler doesn't support subspans or offsets in literal
+/// source can't be determined (i.e. literal contains escapes).
+pub fn lit_str_subspan(
+    lit: &syn::LitStr,
+    range: std::ops::Range<usize>,
+) -> Option<proc_macro2::Span> {
+    let token = lit.token();
+    let repr = token.to_string();
+
+    let prefix = if repr.starts_with('r') {
+        repr.find('"')? + 1
+    } else if repr.contains('\\') {
+        return None;
+    } else {
+        1
+    };
+
+    token.subspan(range.start + prefix..range.end + prefix)
+}
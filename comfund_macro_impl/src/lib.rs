@@ -3,7 +3,9 @@
 
 mod clients;
 mod contract;
+mod endpoint_error;
 mod extensions;
+mod multipart_form;
 mod servers;
 mod utils;
 
@@ -25,6 +27,10 @@ pub fn contract(args: TokenStream, input: TokenStream) -> TokenStream {
         Err(err) => return err.into_compile_error(),
     };
 
+    if let Err(err) = contract.validate() {
+        return err.into_compile_error();
+    }
+
     let mut stream = TokenStream::new();
 
     stream.extend(contract::implement(&contract));
@@ -33,3 +39,21 @@ pub fn contract(args: TokenStream, input: TokenStream) -> TokenStream {
 
     stream
 }
+
+pub fn multipart_form(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<syn::DeriveInput>(input) {
+        Ok(input) => input,
+        Err(err) => return err.into_compile_error(),
+    };
+
+    multipart_form::implement(input).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+pub fn endpoint_error(input: TokenStream) -> TokenStream {
+    let input = match syn::parse2::<syn::DeriveInput>(input) {
+        Ok(input) => input,
+        Err(err) => return err.into_compile_error(),
+    };
+
+    endpoint_error::implement(input).unwrap_or_else(syn::Error::into_compile_error)
+}
@@ -0,0 +1,56 @@
+use quote::quote;
+
+/// Implement `comfund::multipart::MultipartForm` for a struct with named fields.
+pub fn implement(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`MultipartForm` can only be derived for structs with named fields.",
+            ))
+        }
+    };
+
+    let id = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let names = fields
+        .iter()
+        .map(|field| field.ident.as_ref().unwrap())
+        .collect::<Vec<_>>();
+    let part_names = names
+        .iter()
+        .map(|name| syn::LitStr::new(&name.to_string(), name.span()))
+        .collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics ::comfund::multipart::MultipartForm for #id #ty_generics #where_clause {
+            fn into_form(self) -> ::comfund::multipart::Form {
+                let mut form = ::comfund::multipart::Form::new();
+
+                #(
+                    ::comfund::multipart::FormField::into_parts(self.#names, #part_names, &mut form);
+                )*
+
+                form
+            }
+
+            fn from_form(
+                mut form: ::comfund::multipart::Form,
+            ) -> ::std::result::Result<Self, ::comfund::multipart::Error> {
+                Ok(Self {
+                    #(
+                        #names: ::comfund::multipart::FormField::from_parts(
+                            #part_names,
+                            form.take(#part_names),
+                        )?
+                    ),*
+                })
+            }
+        }
+    })
+}
@@ -3,6 +3,7 @@ use syn::{parse_quote, parse_quote_spanned};
 
 use crate::contract::content_type::ContentType;
 use crate::contract::endpoint::Endpoint;
+use crate::contract::inputs::Inputs;
 use crate::contract::method::Method;
 use crate::contract::param::Param;
 use crate::contract::transport::Transport;
@@ -36,7 +37,7 @@ impl<'e> ActixEndpoint<'e> {
 
 impl ActixEndpoint<'_> {
     fn path(&self) -> &syn::LitStr {
-        self.ep.meta.path_lit()
+        &self.ep.route
     }
 
     fn handler_id(&self) -> &syn::Ident {
@@ -57,20 +58,107 @@ impl ActixEndpoint<'_> {
         }
     }
 
+    pub fn impl_extractors(&self) -> impl quote::ToTokens {
+        let header_extractor = self.ep.header_inputs.as_ref().map(impl_extractor);
+        let cookie_extractor = self.ep.cookie_inputs.as_ref().map(impl_extractor);
+
+        quote! {
+            #header_extractor
+            #cookie_extractor
+        }
+    }
+
     pub fn method_router(&self, service_trait_var: &syn::Ident) -> impl quote::ToTokens {
         let mut method: syn::Ident = match self.ep.meta.method() {
             Method::Get => parse_quote!(get),
             Method::Post => parse_quote!(post),
             Method::Delete => parse_quote!(delete),
-            Method::Patch => parse_quote!(update),
+            Method::Patch => parse_quote!(patch),
             Method::Put => parse_quote!(put),
         };
 
         let handler_id = self.handler_id();
         method.set_span(handler_id.span());
 
+        // Declared status replaces default `200 OK` of successful responses
+        let status = self.ep.meta.options().status().map(|status| {
+            quote! {
+                .wrap(::actix_web::middleware::from_fn(
+                    |req: ::actix_web::dev::ServiceRequest,
+                     next: ::actix_web::middleware::Next<::actix_web::body::BoxBody>| async move {
+                        let mut res = next.call(req).await?;
+
+                        if res.status() == ::actix_web::http::StatusCode::OK {
+                            *res.response_mut().status_mut() =
+                                ::actix_web::http::StatusCode::from_u16(#status).unwrap();
+                        }
+
+                        Ok::<_, ::actix_web::Error>(res)
+                    },
+                ))
+            }
+        });
+
         quote! {
-            ::actix_web::web::#method().to(#service_trait_var::#handler_id)
+            ::actix_web::web::#method().to(#service_trait_var::#handler_id) #status
+        }
+    }
+}
+
+/// Implement extractor for inputs, that are parsed from request headers.
+fn impl_extractor(inputs: &Inputs) -> impl quote::ToTokens {
+    let ty = &inputs.ty;
+
+    let fields = inputs.params.iter().map(|param| {
+        let name = &param.name;
+
+        let (raw_value, message) = match param.meta.transport() {
+            Transport::Header(header) => (
+                quote! {
+                    req
+                        .headers()
+                        .get(#header)
+                        .and_then(|value| value.to_str().ok())
+                },
+                format!("Missing or invalid `{}` header.", header.value()),
+            ),
+            Transport::Cookie(cookie) => (
+                quote! {
+                    req
+                        .headers()
+                        .get_all(::actix_web::http::header::COOKIE)
+                        .filter_map(|value| value.to_str().ok())
+                        .find_map(|value| ::comfund::cookie::find(value, #cookie))
+                },
+                format!("Missing or invalid `{}` cookie.", cookie.value()),
+            ),
+            _ => unreachable!("Unexpected transport kind of extracted argument"),
+        };
+
+        quote! {
+            #name: #raw_value
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(|| ::actix_web::error::ErrorBadRequest(#message))?
+        }
+    });
+
+    quote! {
+        impl ::actix_web::FromRequest for #ty {
+            type Error = ::actix_web::Error;
+            type Future = ::std::future::Ready<::std::result::Result<Self, Self::Error>>;
+
+            fn from_request(
+                req: &::actix_web::HttpRequest,
+                _payload: &mut ::actix_web::dev::Payload,
+            ) -> Self::Future {
+                let extract = || -> ::std::result::Result<Self, Self::Error> {
+                    Ok(Self {
+                        #(#fields),*
+                    })
+                };
+
+                ::std::future::ready(extract())
+            }
         }
     }
 }
@@ -117,6 +205,24 @@ fn def_handler(aep: &ActixEndpoint, ext_type_name: &syn::Ident) -> impl quote::T
         });
     });
 
+    aep.ep.header_inputs.as_ref().inspect(|&inputs| {
+        let ty = &inputs.ty;
+
+        fn_args.push(parse_quote_spanned! {
+            handler_id.span()=>
+            header_inputs: #ty
+        });
+    });
+
+    aep.ep.cookie_inputs.as_ref().inspect(|&inputs| {
+        let ty = &inputs.ty;
+
+        fn_args.push(parse_quote_spanned! {
+            handler_id.span()=>
+            cookie_inputs: #ty
+        });
+    });
+
     fn_args.push(parse_quote_spanned! {
         handler_id.span()=>
         extensions: Self::#ext_type_name
@@ -137,7 +243,14 @@ fn def_handler(aep: &ActixEndpoint, ext_type_name: &syn::Ident) -> impl quote::T
     let ret_ty = {
         let ty = aep.ep.ret.clone();
 
-        match aep.ep.meta.options().content_type.clone().unwrap_or_default() {
+        match aep
+            .ep
+            .meta
+            .options()
+            .content_type
+            .clone()
+            .unwrap_or_default()
+        {
             ContentType::ApplicationJson => parse_quote_spanned! {
                 handler_id.span()=>
                 ::actix_web::web::Json<#ty>
@@ -146,6 +259,14 @@ fn def_handler(aep: &ActixEndpoint, ext_type_name: &syn::Ident) -> impl quote::T
         }
     };
 
+    let ret_ty: syn::Type = match &aep.ep.err {
+        Some(err) => parse_quote_spanned! {
+            handler_id.span()=>
+            ::std::result::Result<#ret_ty, ::comfund::ErrorResponse<#err>>
+        },
+        None => ret_ty,
+    };
+
     let item_fn: syn::TraitItemFn = parse_quote_spanned! {
         handler_id.span()=>
         fn #handler_id(#fn_args) -> impl ::std::future::Future<Output = #ret_ty>;
@@ -158,8 +279,10 @@ fn get_body_param_ty(param: &Param) -> syn::Type {
     let ty = &param.ty;
 
     match param.meta.transport() {
+        Transport::Body => ty.clone(),
         Transport::Json => parse_quote!(::actix_web::web::Json<#ty>),
-        Transport::Multipart => parse_quote!(::actix_multipart::form::MultipartForm<#ty>),
+        Transport::Form => parse_quote!(::actix_web::web::Form<#ty>),
+        Transport::Multipart => parse_quote!(::comfund::multipart::Multipart<#ty>),
         _ => unreachable!(),
     }
 }
@@ -7,6 +7,10 @@ use crate::{contract::Contract, servers::actix_web::actix_endpoint::ActixEndpoin
 pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
     let service_trait_def = def_service_trait(contract);
     let configure_fn_impl = impl_configure_fn(contract);
+    let extractors = contract
+        .endpoints
+        .iter()
+        .map(|ep| ActixEndpoint::new(ep).impl_extractors());
     let attrs = contract.attrs.iter();
 
     quote! {
@@ -26,6 +30,7 @@ pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
             #(#attrs)*
             #service_trait_def
             #configure_fn_impl
+            #(#extractors)*
         }
     }
 }
@@ -39,9 +44,13 @@ fn def_service_trait(contract: &Contract) -> impl quote::ToTokens {
         .collect::<Vec<_>>();
 
     let ep_trait_items = actix_eps.iter().map(ActixEndpoint::def_in_trait);
+    let supertraits = contract
+        .supertraits
+        .iter()
+        .map(|supertrait| supertrait.item_path(1, Some("actix_web"), supertrait.id()));
 
     quote! {
-        pub trait #contract_id: 'static {
+        pub trait #contract_id: 'static #(+ #supertraits)* {
             #(#ep_trait_items)*
         }
     }
@@ -52,10 +61,30 @@ fn impl_configure_fn(contract: &Contract) -> impl quote::ToTokens {
     let configure_fn_id = get_configure_fn_id(contract_id);
     let service_trait_var = format_ident!("C");
     let routing_expressions = get_routing_expressions(contract, &service_trait_var);
+    let merge_fn_id = get_merge_fn_id(contract_id);
+    let contract_name = contract_id.to_string();
+    let inherited_merge_fns = contract.supertraits.iter().map(|supertrait| {
+        supertrait.item_path(1, Some("actix_web"), &get_merge_fn_id(supertrait.id()))
+    });
 
     quote! {
         pub fn #configure_fn_id<#service_trait_var: #contract_id>(cfg: &mut ::actix_web::web::ServiceConfig) {
+            #merge_fn_id::<#service_trait_var>(cfg, &mut ::std::collections::HashSet::new());
+        }
+
+        /// Configure endpoints of contract, unless contract is already `merged`
+        /// (i.e. when it's inherited through several contracts).
+        #[doc(hidden)]
+        pub fn #merge_fn_id<#service_trait_var: #contract_id>(
+            cfg: &mut ::actix_web::web::ServiceConfig,
+            merged: &mut ::std::collections::HashSet<&'static str>,
+        ) {
+            if !merged.insert(::core::concat!(::core::module_path!(), "::", #contract_name)) {
+                return;
+            }
+
             cfg #(#routing_expressions)*;
+            #(#inherited_merge_fns::<#service_trait_var>(cfg, merged);)*
         }
     }
 }
@@ -68,6 +97,14 @@ fn get_configure_fn_id(contract_id: &syn::Ident) -> syn::Ident {
     syn::Ident::new(&configure_fn_str, contract_id.span())
 }
 
+fn get_merge_fn_id(contract_id: &syn::Ident) -> syn::Ident {
+    let merge_fn_str = format!(
+        "__merge_{}",
+        stringcase::snake_case(&contract_id.to_string())
+    );
+    syn::Ident::new(&merge_fn_str, contract_id.span())
+}
+
 fn get_routing_expressions(
     contract: &Contract,
     service_trait_var: &syn::Ident,
@@ -76,10 +113,7 @@ fn get_routing_expressions(
 
     let mut ep_map = HashMap::with_capacity(contract.endpoints.len());
     for ep in &contract.endpoints {
-        ep_map
-            .entry(ep.meta.path_lit())
-            .or_insert_with(Vec::new)
-            .push(ep)
+        ep_map.entry(&ep.route).or_insert_with(Vec::new).push(ep)
     }
 
     let mut exprs = Vec::with_capacity(ep_map.len());
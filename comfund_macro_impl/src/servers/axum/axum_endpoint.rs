@@ -3,11 +3,13 @@ use syn::{parse_quote, parse_quote_spanned, token};
 
 use crate::contract::content_type::ContentType;
 use crate::contract::endpoint::Endpoint;
+use crate::contract::inputs::Inputs;
 use crate::contract::method::Method;
 use crate::contract::param::Param;
 use crate::contract::transport::Transport;
 
 pub struct AxumEndpoint<'e> {
+    contract_id: &'e syn::Ident,
     ep: &'e Endpoint,
     handler_name: syn::Ident,
     decorator_id: syn::Ident,
@@ -15,7 +17,7 @@ pub struct AxumEndpoint<'e> {
 }
 
 impl<'e> AxumEndpoint<'e> {
-    pub fn new(ep: &'e Endpoint) -> Self {
+    pub fn new(contract_id: &'e syn::Ident, ep: &'e Endpoint) -> Self {
         let handler_name = ep.id.clone();
         let mut handler_str = handler_name.to_string();
 
@@ -29,6 +31,7 @@ impl<'e> AxumEndpoint<'e> {
         };
 
         Self {
+            contract_id,
             ep,
             handler_name,
             decorator_id: decorator_name,
@@ -37,7 +40,7 @@ impl<'e> AxumEndpoint<'e> {
     }
 
     fn path(&self) -> &syn::LitStr {
-        self.ep.meta.path_lit()
+        &self.ep.route
     }
 
     fn handler_id(&self) -> &syn::Ident {
@@ -52,8 +55,15 @@ impl<'e> AxumEndpoint<'e> {
         &self.ext_type_name
     }
 
+    /// State of service (qualified, as inherited contracts have their own states).
+    fn state_ty(&self) -> syn::Type {
+        let contract_id = self.contract_id;
+
+        parse_quote!(<Self as #contract_id>::State)
+    }
+
     pub fn def_in_trait(&self) -> impl quote::ToTokens {
-        let ext_type_def = def_ext_type(self.ext_type_name());
+        let ext_type_def = def_ext_type(self.ext_type_name(), &self.state_ty());
         let handler_def = def_handler(self, self.ext_type_name());
         let decorator_def = def_decorator(self);
 
@@ -64,32 +74,112 @@ impl<'e> AxumEndpoint<'e> {
         }
     }
 
+    pub fn impl_extractors(&self) -> impl quote::ToTokens {
+        let header_extractor = self.ep.header_inputs.as_ref().map(impl_extractor);
+        let cookie_extractor = self.ep.cookie_inputs.as_ref().map(impl_extractor);
+
+        quote! {
+            #header_extractor
+            #cookie_extractor
+        }
+    }
+
     pub fn method_router(&self, service_trait_var: &syn::Ident) -> impl quote::ToTokens {
         let method: syn::Ident = match self.ep.meta.method() {
             Method::Get => parse_quote!(get),
             Method::Post => parse_quote!(post),
             Method::Delete => parse_quote!(delete),
-            Method::Patch => parse_quote!(update),
+            Method::Patch => parse_quote!(patch),
             Method::Put => parse_quote!(put),
         };
 
         let handler_id = self.handler_id();
         let decorator_id = self.decorator_id();
 
+        // Declared status replaces default `200 OK` of successful responses
+        let status = self.ep.meta.options().status().map(|status| {
+            quote! {
+                .layer(::axum::middleware::map_response(
+                    |mut response: ::axum::response::Response| async move {
+                        if response.status() == ::axum::http::StatusCode::OK {
+                            *response.status_mut() = ::axum::http::StatusCode::from_u16(#status).unwrap();
+                        }
+
+                        response
+                    },
+                ))
+            }
+        });
+
         quote! {
             ::axum::routing::#method(
                 #service_trait_var::#decorator_id(
                     #service_trait_var::#handler_id
                 )
             )
+            #status
         }
     }
 }
 
-fn def_ext_type(ext_type_name: &syn::Ident) -> impl quote::ToTokens {
+/// Implement extractor for inputs, that are parsed from request headers.
+fn impl_extractor(inputs: &Inputs) -> impl quote::ToTokens {
+    let ty = &inputs.ty;
+
+    let fields = inputs.params.iter().map(|param| {
+        let name = &param.name;
+
+        let (raw_value, message) = match param.meta.transport() {
+            Transport::Header(header) => (
+                quote! {
+                    parts
+                        .headers
+                        .get(#header)
+                        .and_then(|value| value.to_str().ok())
+                },
+                format!("Missing or invalid `{}` header.", header.value()),
+            ),
+            Transport::Cookie(cookie) => (
+                quote! {
+                    parts
+                        .headers
+                        .get_all(::axum::http::header::COOKIE)
+                        .iter()
+                        .filter_map(|value| value.to_str().ok())
+                        .find_map(|value| ::comfund::cookie::find(value, #cookie))
+                },
+                format!("Missing or invalid `{}` cookie.", cookie.value()),
+            ),
+            _ => unreachable!("Unexpected transport kind of extracted argument"),
+        };
+
+        quote! {
+            #name: #raw_value
+                .and_then(|value| value.parse().ok())
+                .ok_or((::axum::http::StatusCode::BAD_REQUEST, #message))?
+        }
+    });
+
+    quote! {
+        impl<S: ::std::marker::Send + ::std::marker::Sync> ::axum::extract::FromRequestParts<S> for #ty {
+            type Rejection = (::axum::http::StatusCode, &'static str);
+
+            async fn from_request_parts(
+                parts: &mut ::axum::http::request::Parts,
+                _state: &S,
+            ) -> ::std::result::Result<Self, Self::Rejection> {
+                Ok(Self {
+                    #(#fields),*
+                })
+            }
+        }
+    }
+}
+
+fn def_ext_type(ext_type_name: &syn::Ident, state_ty: &syn::Type) -> impl quote::ToTokens {
     let item_type: syn::TraitItemType = parse_quote_spanned! {
         ext_type_name.span()=>
-        type #ext_type_name: ::axum::extract::FromRequestParts<Self::State> + ::std::marker::Send;
+        type #ext_type_name: ::axum::extract::FromRequestParts<#state_ty> + ::std::marker::Send;
     };
 
     item_type
@@ -120,6 +210,18 @@ fn def_handler(aep: &AxumEndpoint, ext_type_name: &syn::Ident) -> impl quote::To
         fn_args.push(parse_quote!(#id: ::axum::extract::Query<#ty>));
     });
 
+    aep.ep.header_inputs.as_ref().inspect(|&inputs| {
+        let ty = &inputs.ty;
+
+        fn_args.push(parse_quote!(header_inputs: #ty));
+    });
+
+    aep.ep.cookie_inputs.as_ref().inspect(|&inputs| {
+        let ty = &inputs.ty;
+
+        fn_args.push(parse_quote!(cookie_inputs: #ty));
+    });
+
     fn_args.push(parse_quote!(extensions: Self::#ext_type_name));
 
     aep.ep.body_param.as_ref().inspect(|&param| {
@@ -136,13 +238,25 @@ fn def_handler(aep: &AxumEndpoint, ext_type_name: &syn::Ident) -> impl quote::To
     let ret_ty = {
         let ret_ty = aep.ep.ret.clone();
 
-        match aep.ep.meta.options().content_type.clone().unwrap_or_default() {
+        match aep
+            .ep
+            .meta
+            .options()
+            .content_type
+            .clone()
+            .unwrap_or_default()
+        {
             // TODO: Response types mapping when defined common supported returned content types
             ContentType::ApplicationJson => parse_quote!(::axum::Json<#ret_ty>),
             _ => ret_ty,
         }
     };
 
+    let ret_ty: syn::Type = match &aep.ep.err {
+        Some(err) => parse_quote!(::std::result::Result<#ret_ty, ::comfund::ErrorResponse<#err>>),
+        None => ret_ty,
+    };
+
     let item_fn: syn::TraitItemFn = parse_quote! {
         fn #handler_id(#fn_args) -> impl ::std::future::Future<Output = #ret_ty> + Send;
     };
@@ -159,21 +273,32 @@ fn def_decorator(aep: &AxumEndpoint) -> impl quote::ToTokens {
         let ty = &inputs.ty;
         quote!(,::axum::extract::Query<#ty>)
     });
+    let header_ty = aep.ep.header_inputs.as_ref().map(|inputs| {
+        let ty = &inputs.ty;
+        quote!(,#ty)
+    });
+    let cookie_ty = aep.ep.cookie_inputs.as_ref().map(|inputs| {
+        let ty = &inputs.ty;
+        quote!(,#ty)
+    });
     let ext_ty = aep.ext_type_name();
     let body_ty = aep.ep.body_param.as_ref().map(|param| {
         let ty = get_body_param_ty(param);
         quote!(,#ty)
     });
     let decorator_id = aep.decorator_id();
+    let state_ty = aep.state_ty();
 
     let handler_constraint = quote! {
         impl ::axum::handler::Handler<(
             M
             #path_ty
             #query_ty
+            #header_ty
+            #cookie_ty
             , Self::#ext_ty
             #body_ty
-        ), Self::State>
+        ), #state_ty>
     };
 
     let item_fn: syn::TraitItemFn = parse_quote! {
@@ -191,8 +316,10 @@ fn get_body_param_ty(param: &Param) -> syn::Type {
     let ty = &param.ty;
 
     match param.meta.transport() {
+        Transport::Body => ty.clone(),
         Transport::Json => parse_quote!(::axum::extract::Json<#ty>),
-        Transport::Multipart => parse_quote!(::axum::extract::Multipart<#ty>),
+        Transport::Form => parse_quote!(::axum::extract::Form<#ty>),
+        Transport::Multipart => parse_quote!(::comfund::multipart::Multipart<#ty>),
         _ => unreachable!(),
     }
 }
@@ -1,6 +1,7 @@
 mod axum_endpoint;
 
 use quote::{format_ident, quote, ToTokens};
+use syn::parse_quote;
 
 use crate::contract::Contract;
 use crate::servers::axum::axum_endpoint::AxumEndpoint;
@@ -8,6 +9,10 @@ use crate::servers::axum::axum_endpoint::AxumEndpoint;
 pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
     let service_trait_def = def_service_trait(contract);
     let route_fn_impl = impl_route_function(contract);
+    let extractors = contract
+        .endpoints
+        .iter()
+        .map(|ep| AxumEndpoint::new(&contract.id, ep).impl_extractors());
     let attrs = contract.attrs.iter();
 
     quote! {
@@ -20,6 +25,7 @@ pub fn implement(contract: &Contract) -> proc_macro2::TokenStream {
             #(#attrs)*
             #service_trait_def
             #route_fn_impl
+            #(#extractors)*
         }
     }
 }
@@ -30,13 +36,30 @@ fn def_service_trait(contract: &Contract) -> impl quote::ToTokens {
     let axum_eps = contract
         .endpoints
         .iter()
-        .map(AxumEndpoint::new)
+        .map(|ep| AxumEndpoint::new(contract_id, ep))
         .collect::<Vec<_>>();
     let ep_trait_items = axum_eps.iter().map(AxumEndpoint::def_in_trait);
+    let supertraits = contract
+        .supertraits
+        .iter()
+        .map(|supertrait| supertrait.item_path(1, Some("axum"), supertrait.id()))
+        .collect::<Vec<_>>();
+
+    // Bounds on associated types are implied, so routers of inherited contracts
+    // can be built from this state at any depth of inheritance.
+    let substates = supertraits.iter().map(|supertrait| {
+        quote! {
+            + ::comfund::substate::Substate<<Self as #supertrait>::State>
+        }
+    });
 
     quote! {
-        pub trait #contract_id: 'static {
-            type State: 'static + ::core::marker::Send + ::core::marker::Sync + ::core::clone::Clone;
+        pub trait #contract_id: 'static #(+ #supertraits)* {
+            type State: 'static
+                + ::core::marker::Send
+                + ::core::marker::Sync
+                + ::core::clone::Clone
+                #(#substates)*;
 
             #(#ep_trait_items)*
         }
@@ -46,14 +69,57 @@ fn def_service_trait(contract: &Contract) -> impl quote::ToTokens {
 fn impl_route_function(contract: &Contract) -> impl quote::ToTokens {
     let contract_id = &contract.id;
     let route_fn_id = get_route_fn_id(&contract.id);
+    let merge_fn_id = get_merge_fn_id(&contract.id);
     let service_trait_var = format_ident!("C");
     let routing_expressions = get_routing_expressions(contract, &service_trait_var);
+    let state_ty: syn::Type = parse_quote!(<#service_trait_var as #contract_id>::State);
+    let contract_name = contract_id.to_string();
+
+    // Routers of inherited contracts are merged with their own states,
+    // derived from the state of this contract.
+    let inherited_routers = contract.supertraits.iter().map(|supertrait| {
+        let merge_fn_path =
+            supertrait.item_path(1, Some("axum"), &get_merge_fn_id(supertrait.id()));
+
+        quote! {
+            let router = #merge_fn_path::<#service_trait_var>(
+                router,
+                ::comfund::substate::Substate::substate(&state),
+                merged,
+            );
+        }
+    });
 
     quote! {
-        pub fn #route_fn_id<#service_trait_var: #contract_id>(state: #service_trait_var::State) -> ::axum::Router<#service_trait_var::State> {
-            ::axum::Router::new()
-                #(#routing_expressions)*
-                .with_state(state)
+        pub fn #route_fn_id<#service_trait_var: #contract_id>(state: #state_ty) -> ::axum::Router<#state_ty> {
+            #merge_fn_id::<#service_trait_var>(
+                ::axum::Router::new(),
+                state,
+                &mut ::std::collections::HashSet::new(),
+            )
+            .with_state(())
+        }
+
+        /// Merge endpoints of contract into `router`, unless contract is already `merged`
+        /// (i.e. when it's inherited through several contracts).
+        #[doc(hidden)]
+        pub fn #merge_fn_id<#service_trait_var: #contract_id>(
+            router: ::axum::Router,
+            state: #state_ty,
+            merged: &mut ::std::collections::HashSet<&'static str>,
+        ) -> ::axum::Router {
+            if !merged.insert(::core::concat!(::core::module_path!(), "::", #contract_name)) {
+                return router;
+            }
+
+            let router = router.merge(
+                ::axum::Router::new()
+                    #(#routing_expressions)*
+                    .with_state(::core::clone::Clone::clone(&state))
+            );
+            #(#inherited_routers)*
+
+            router
         }
     }
 }
@@ -63,6 +129,14 @@ fn get_route_fn_id(contract_id: &syn::Ident) -> syn::Ident {
     syn::Ident::new(&route_fn_str, contract_id.span())
 }
 
+fn get_merge_fn_id(contract_id: &syn::Ident) -> syn::Ident {
+    let merge_fn_str = format!(
+        "__merge_{}",
+        stringcase::snake_case(&contract_id.to_string())
+    );
+    syn::Ident::new(&merge_fn_str, contract_id.span())
+}
+
 fn get_routing_expressions(
     contract: &Contract,
     service_trait_var: &syn::Ident,
@@ -71,10 +145,7 @@ fn get_routing_expressions(
 
     let mut ep_map = HashMap::with_capacity(contract.endpoints.len());
     for ep in &contract.endpoints {
-        ep_map
-            .entry(ep.meta.path_lit())
-            .or_insert_with(Vec::new)
-            .push(ep);
+        ep_map.entry(&ep.route).or_insert_with(Vec::new).push(ep);
     }
 
     let mut exprs = Vec::with_capacity(ep_map.len());
@@ -82,10 +153,14 @@ fn get_routing_expressions(
     for (path, eps) in ep_map {
         let method_router_exprs = eps
             .into_iter()
-            .map(|ep| AxumEndpoint::new(ep).method_router(service_trait_var));
+            .map(|ep| AxumEndpoint::new(&contract.id, ep).method_router(service_trait_var));
 
         let expr = quote! {
-            .route(#path, #(#method_router_exprs).*)
+            .route(
+                #path,
+                ::axum::routing::MethodRouter::new()
+                    #(.merge(#method_router_exprs))*
+            )
         };
 
         exprs.push(expr);
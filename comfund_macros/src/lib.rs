@@ -7,6 +7,11 @@
 //!
 //! For client side, either
 //! a stateful client or static implementation will be generated.  
+//! With `reqwest-blocking` feature, a synchronous `[contract_name]BlockingClient` is
+//! generated as well.
+//! Clients are built on top of generated `codec` module, with `encode_[endpoint_name]` and
+//! `decode_[endpoint_name]` functions, that convert calls of endpoints into `http::Request`s
+//! and `http::Response`s into return values without any IO.
 //!
 //! For server side, a service trait will be generated. Implementation of this trait
 //! can then be passed to a generated configure function to create configuration/router with
@@ -30,11 +35,30 @@
 //! }
 //! ```
 //!
+//! Endpoints can be declared with `async fn` as well. Both spellings are equivalent, as
+//! generated client and server code is asynchronous (except for blocking clients).
+//!
+//! ```
+//! use comfund::contract;
+//!
+//! #[contract]
+//! pub trait Service {
+//!     #[endpoint(get, "/")]
+//!     async fn endpoint() -> String;
+//! }
+//! ```
+//!
 //! Endpoints can accept parameters. Each parameter should be annotated with `#[param]`
 //! attribute with one required arg - type of transport:
 //! - through endpoint URL path (`path`),
 //! - URL query param (`query`)
-//! - Request body (`plain text` or `json`)
+//! - Request header with given name (`header = "X-Header-Name"`)
+//! - Request cookie with given name (`cookie = "name"`)
+//! - Request body (`plain text`, `json`, urlencoded `form` or `multipart` for types,
+//!   implementing `comfund::multipart::MultipartForm`)
+//!
+//! Header and cookie params are converted with [`ToString`] on client side and parsed with
+//! [`FromStr`](std::str::FromStr) on server side.
 //!
 //! ```
 //! use comfund::contract;
@@ -46,20 +70,38 @@
 //!     
 //!     #[endpoint(get, "/query")]
 //!     fn query(#[param(query)] a: String);
+//!
+//!     #[endpoint(get, "/header")]
+//!     fn header(#[param(header = "X-Tenant-Id")] tenant: u32);
+//!
+//!     #[endpoint(get, "/cookie")]
+//!     fn cookie(#[param(cookie = "session")] session: String);
 //!     
 //!     #[endpoint(post, "/body")]
-//!     fn body(#[param(body)]) a: String);
+//!     fn body(#[param(body)] a: String);
 //!     
 //!     #[endpoint(post, "/body/json")]
-//!     fn json(#[param(json)]) a: Vec<String>);
+//!     fn json(#[param(json)] a: Vec<String>);
+//!
+//!     #[endpoint(post, "/body/form")]
+//!     fn form(#[param(form)] a: std::collections::HashMap<String, String>);
 //! }
 //! ```
 //!
 //! Endpoints can also have return types. If you want to be able to return/read error info as well,
-//! you can set [`Result`] as return type.
+//! you can set [`Result`] as return type. Error types should implement `comfund::EndpointError`
+//! and are sent as JSON bodies of responses with error status codes.
 //!
 //! ```
 //! use comfund::contract;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Debug, Serialize, Deserialize)]
+//! pub struct Error {
+//!     message: String,
+//! }
+//!
+//! impl comfund::EndpointError for Error {}
 //!
 //! #[contract]
 //! pub trait Service {
@@ -71,15 +113,63 @@
 //! }
 //! ```
 //!
+//! Contract-wide default error type can be set with `error` option of contract. Endpoints,
+//! that return `Result<T>`, use it as their error type. `comfund::Problem` provides RFC 7807
+//! (`application/problem+json`) errors out of the box.
+//!
+//! ```
+//! use comfund::contract;
+//!
+//! #[contract(error = comfund::Problem)]
+//! pub trait Service {
+//!     // Returns `Result<String, comfund::Problem>`
+//!     #[endpoint(get, "/may_fail")]
+//!     fn may_fail() -> Result<String>;
+//! }
+//! ```
+//!
+//! Common path prefix of all endpoints can be set with `prefix` option of contract. It is
+//! prepended to endpoint paths by both generated clients and servers.
+//!
+//! ```
+//! use comfund::contract;
+//!
+//! #[contract(prefix = "/api/v1")]
+//! pub trait Service {
+//!     // Mounted on `/api/v1/items`
+//!     #[endpoint(get, "/items")]
+//!     fn items() -> Vec<String>;
+//! }
+//! ```
+//!
+//! Contracts can inherit endpoints of other contracts through supertraits. Supertraits should
+//! be specified by path to the module of inherited contract. Clients expose inherited endpoints
+//! through generated `[contract_name]Api` traits.
+//!
+//! ```
+//! mod users {
+//!     #[comfund::contract]
+//!     pub trait Users {
+//!         #[endpoint(get, "/users")]
+//!         fn users() -> String;
+//!     }
+//! }
+//!
+//! #[comfund::contract]
+//! pub trait Admin: users::Users {
+//!     #[endpoint(get, "/stats")]
+//!     fn stats() -> String;
+//! }
+//! ```
+//!
 //! Endpoints can also specify `content-type` for returned value. Generated server and client code will
 //! handle the conversion accordingly.
 //!
 //! ```
 //! use comfund::contract;
-//! use comfund::serde::{Serialize, Deserialize};
+//! use serde::{Serialize, Deserialize};
 //!
 //! #[derive(Serialize, Deserialize)]
-//! #[serde(crate = comfund::serde)]
 //! struct Return {
 //!     status: u16,
 //!     string: String
@@ -104,3 +194,38 @@ use proc_macro::TokenStream;
 pub fn contract(args: TokenStream, input: TokenStream) -> TokenStream {
     comfund_macro_impl::contract(args.into(), input.into()).into()
 }
+
+/// # `MultipartForm` derive macro
+///
+/// Implements `comfund::multipart::MultipartForm` for a struct with named fields,
+/// so that it can be passed as `#[param(multipart)]` endpoint param. Every field should
+/// implement `comfund::multipart::FormField` and is sent as form part(s) named after the field.
+#[proc_macro_derive(MultipartForm)]
+pub fn multipart_form(input: TokenStream) -> TokenStream {
+    comfund_macro_impl::multipart_form(input.into()).into()
+}
+
+/// # `EndpointError` derive macro
+///
+/// Implements `comfund::EndpointError` with status codes, declared by `#[status(code)]`
+/// attributes. Attribute of enum sets default status of its variants, attributes of variants
+/// override it. Errors without declared status are sent with `500 Internal Server Error`.
+///
+/// ```
+/// use comfund::EndpointError;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Debug, Serialize, Deserialize, EndpointError)]
+/// #[status(400)]
+/// pub enum UserError {
+///     #[status(404)]
+///     NotFound,
+///     #[status(409)]
+///     AlreadyExists { name: String },
+///     InvalidName(String),
+/// }
+/// ```
+#[proc_macro_derive(EndpointError, attributes(status))]
+pub fn endpoint_error(input: TokenStream) -> TokenStream {
+    comfund_macro_impl::endpoint_error(input.into()).into()
+}
@@ -1,4 +1,5 @@
 use std::fmt::Display;
+use std::ops::Range;
 
 type Result<T> = std::result::Result<T, Error>;
 
@@ -41,6 +42,7 @@ impl PathTemplate<'static> {
 impl<'s> PathTemplate<'s> {
     /// Parse dynamic path expression, normalizing it in the process.
     pub fn new(expr: &'s str) -> Result<Self> {
+        let source = expr;
         let expr = expr.trim_end_matches('/');
         if expr.is_empty() {
             return Ok(Self {
@@ -50,7 +52,7 @@ impl<'s> PathTemplate<'s> {
             });
         }
 
-        let (expr, wildcard) = trim_wildcard(expr)?;
+        let (expr, wildcard) = trim_wildcard(source, expr)?;
         let mut segments = vec![];
         let mut idents = vec![];
 
@@ -59,18 +61,18 @@ impl<'s> PathTemplate<'s> {
                 continue;
             }
 
-            let capture = get_capture(seg)?;
+            let capture = get_capture(source, seg)?;
 
             if let Some(ident) = capture {
                 if ident.starts_with('*') {
-                    return Err(Error::InvalidWildcard);
+                    return Err(Error::InvalidWildcard(offsets(source, seg)));
                 } else {
-                    let ident = assert_ident(ident)?;
+                    let ident = assert_ident(source, ident)?;
                     segments.push(Segment::Capture(ident));
                     idents.push(ident);
                 }
             } else {
-                let seg = assert_url_segment(seg)?;
+                let seg = assert_url_segment(source, seg)?;
                 segments.push(Segment::Static(seg));
             }
         }
@@ -172,26 +174,40 @@ pub enum Segment<'s> {
 }
 
 /// An error type for parsing dynamic URL paths.
+///
+/// Each variant contains byte offsets of offending part of parsed expression.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Error {
-    UnclosedCapture,
+    UnclosedCapture(Range<usize>),
     /// Wildcard captures are only accepted at the end of dynamic path
-    InvalidWildcard,
+    InvalidWildcard(Range<usize>),
     /// Capture valriable wasn't a valid Rust ident.
-    InvalidIdent,
+    InvalidIdent(Range<usize>),
     /// Static segment contained invalid URL path character.
-    InvalidPathChar,
+    InvalidPathChar(Range<usize>),
+}
+
+impl Error {
+    /// Get byte offsets of offending part of parsed expression.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            Self::UnclosedCapture(span)
+            | Self::InvalidWildcard(span)
+            | Self::InvalidIdent(span)
+            | Self::InvalidPathChar(span) => span.clone(),
+        }
+    }
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::UnclosedCapture => write!(f, "unclosed capture"),
-            Self::InvalidWildcard => {
+            Self::UnclosedCapture(_) => write!(f, "unclosed capture"),
+            Self::InvalidWildcard(_) => {
                 write!(f, "wildcard can only be the last capture in path template")
             }
-            Self::InvalidIdent => write!(f, "capture ident should be a valid Rust ident"),
-            Self::InvalidPathChar => write!(
+            Self::InvalidIdent(_) => write!(f, "capture ident should be a valid Rust ident"),
+            Self::InvalidPathChar(_) => write!(
                 f,
                 "static segments of template should be valid url path substrings"
             ),
@@ -204,20 +220,29 @@ fn is_valid_ident(segment: &str) -> bool {
         && segment.chars().all(|ch| ch.is_alphanumeric() || ch == '_')
 }
 
-fn assert_ident(seg: &str) -> Result<&str> {
+/// Get byte offsets of `part` inside of `source`.
+///
+/// `part` should be a substring slice of `source`.
+fn offsets(source: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - source.as_ptr() as usize;
+
+    start..start + part.len()
+}
+
+fn assert_ident<'s>(source: &str, seg: &'s str) -> Result<&'s str> {
     if is_valid_ident(seg) {
         Ok(seg)
     } else {
-        Err(Error::InvalidIdent)
+        Err(Error::InvalidIdent(offsets(source, seg)))
     }
 }
 
-fn get_wildcard(seg: &str) -> Result<Option<&str>> {
-    let capture = get_capture(seg)?;
+fn get_wildcard<'s>(source: &str, seg: &'s str) -> Result<Option<&'s str>> {
+    let capture = get_capture(source, seg)?;
 
     if let Some(capture) = capture {
         if let Some(ident) = capture.strip_prefix('*') {
-            assert_ident(ident)?;
+            assert_ident(source, ident)?;
             Ok(Some(ident))
         } else {
             Ok(None)
@@ -227,9 +252,9 @@ fn get_wildcard(seg: &str) -> Result<Option<&str>> {
     }
 }
 
-fn trim_wildcard(expr: &str) -> Result<(&str, Option<&str>)> {
+fn trim_wildcard<'s>(source: &str, expr: &'s str) -> Result<(&'s str, Option<&'s str>)> {
     let last_segment = expr.rsplit('/').next().unwrap();
-    let wildcard = get_wildcard(last_segment)?;
+    let wildcard = get_wildcard(source, last_segment)?;
 
     let expr = if wildcard.is_some() {
         expr.trim_end_matches(last_segment)
@@ -240,12 +265,12 @@ fn trim_wildcard(expr: &str) -> Result<(&str, Option<&str>)> {
     Ok((expr, wildcard))
 }
 
-fn get_capture(seg: &str) -> Result<Option<&str>> {
+fn get_capture<'s>(source: &str, seg: &'s str) -> Result<Option<&'s str>> {
     let capture_start = seg.starts_with('{');
     let capture_end = seg.ends_with('}');
 
     if capture_start ^ capture_end {
-        return Err(Error::UnclosedCapture);
+        return Err(Error::UnclosedCapture(offsets(source, seg)));
     }
 
     if capture_start & capture_end {
@@ -282,11 +307,15 @@ fn is_valid_url_path_char(ch: char) -> bool {
     )
 }
 
-fn assert_url_segment(seg: &str) -> Result<&str> {
-    if seg.chars().all(is_valid_url_path_char) {
-        Ok(seg)
-    } else {
-        Err(Error::InvalidPathChar)
+fn assert_url_segment<'s>(source: &str, seg: &'s str) -> Result<&'s str> {
+    match seg.find(|ch| !is_valid_url_path_char(ch)) {
+        None => Ok(seg),
+        Some(pos) => {
+            let ch_len = seg[pos..].chars().next().unwrap().len_utf8();
+            let start = offsets(source, seg).start + pos;
+
+            Err(Error::InvalidPathChar(start..start + ch_len))
+        }
     }
 }
 
@@ -455,10 +484,8 @@ mod tests {
         let parsed = PathTemplate::new("/{a/b/c");
         let parsed2 = PathTemplate::new("/a/b}/c/d");
 
-        let error = Err(Error::UnclosedCapture);
-
-        assert_eq!(parsed, error);
-        assert_eq!(parsed2, error);
+        assert_eq!(parsed, Err(Error::UnclosedCapture(1..3)));
+        assert_eq!(parsed2, Err(Error::UnclosedCapture(3..5)));
     }
 
     #[test]
@@ -468,7 +495,7 @@ mod tests {
         let parsed3 = PathTemplate::new("/a/{b.s}/c/d");
         let parsed4 = PathTemplate::new("/a/{11b}/c/d");
 
-        let error: Result<PathTemplate<'_>, Error> = Err(Error::InvalidIdent);
+        let error: Result<PathTemplate<'_>, Error> = Err(Error::InvalidIdent(4..7));
 
         assert_eq!(parsed, error.clone());
         assert_eq!(parsed2, error.clone());
@@ -479,8 +506,24 @@ mod tests {
     #[test]
     fn test_invalid_wildcard() {
         let parsed = PathTemplate::new("/a/{*bs}/c/");
-        let error = Err(Error::InvalidWildcard);
+        let error = Err(Error::InvalidWildcard(3..8));
 
         assert_eq!(parsed, error);
     }
+
+    #[test]
+    fn test_invalid_path_char() {
+        let parsed = PathTemplate::new("/a/b c/{d}");
+        let parsed2 = PathTemplate::new("/a/{d}/ö");
+
+        assert_eq!(parsed, Err(Error::InvalidPathChar(4..5)));
+        assert_eq!(parsed2, Err(Error::InvalidPathChar(7..9)));
+    }
+
+    #[test]
+    fn test_invalid_wildcard_ident() {
+        let parsed = PathTemplate::new("/a/{*1b}/");
+
+        assert_eq!(parsed, Err(Error::InvalidIdent(5..7)));
+    }
 }
@@ -0,0 +1,171 @@
+//! Test servers for services, implementing `comfund` contracts.
+//!
+//! Server is spawned on a port, assigned by OS, on a dedicated thread with its own runtime,
+//! so that tests can run in parallel and outlive runtimes of each other. Returned
+//! [`TestServer`] dereferences to generated client, pointed at server, and stops server,
+//! when dropped.
+//!
+//! ```ignore
+//! let server = comfund_test::axum::<CounterServiceClient>(
+//!     axum::route_counter_service::<CounterServiceImpl>(state),
+//! );
+//!
+//! assert_eq!(server.get_current().await?, 0);
+//! ```
+
+use std::net::SocketAddr;
+use std::thread::JoinHandle;
+
+use tokio::sync::oneshot;
+
+/// Running test server with client of contract, pointed at it.
+///
+/// Server is stopped (and its thread is joined), when `TestServer` is dropped.
+pub struct TestServer<C> {
+    client: C,
+    addr: SocketAddr,
+    url: String,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<C> TestServer<C> {
+    /// Client of contract with root url of server.
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Address, server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Root url of server, i.e. `http://127.0.0.1:43210`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl<C> std::ops::Deref for TestServer<C> {
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        &self.client
+    }
+}
+
+impl<C> Drop for TestServer<C> {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            // Server may have stopped on its own already
+            let _ = shutdown.send(());
+        }
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl<C: std::fmt::Debug> std::fmt::Debug for TestServer<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestServer")
+            .field("client", &self.client)
+            .field("addr", &self.addr)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Spawn server of `axum` with `router` (i.e. returned by generated `route_[contract_name]`
+/// function) on ephemeral port.
+///
+/// # Panics
+///
+/// Panics, if port can't be bound.
+#[cfg(feature = "axum")]
+pub fn axum<C>(router: axum::Router) -> TestServer<C>
+where
+    C: comfund::ContractClient,
+    C::Transport: Default,
+{
+    spawn(move |listener, shutdown| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(async move {
+                listener.set_nonblocking(true).unwrap();
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+
+                // Connections are dropped together with runtime
+                tokio::select! {
+                    result = async { axum::serve(listener, router).await } => result.unwrap(),
+                    _ = shutdown => {}
+                }
+            });
+    })
+}
+
+/// Spawn server of `actix-web` with app, configured by `configure` (i.e. generated
+/// `configure_[contract_name]` function) on ephemeral port.
+///
+/// # Panics
+///
+/// Panics, if port can't be bound.
+#[cfg(feature = "actix-web")]
+pub fn actix<C, F>(configure: F) -> TestServer<C>
+where
+    C: comfund::ContractClient,
+    C::Transport: Default,
+    F: FnOnce(&mut actix_web::web::ServiceConfig) + Clone + Send + 'static,
+{
+    spawn(move |listener, shutdown| {
+        actix_web::rt::System::new().block_on(async move {
+            let factory = move || actix_web::App::new().configure(configure.clone());
+            let server = actix_web::HttpServer::new(factory)
+                .workers(1)
+                .disable_signals()
+                .listen(listener)
+                .unwrap()
+                .run();
+            let handle = server.handle();
+
+            actix_web::rt::spawn(async move {
+                // Signalled (or dropped) by `TestServer` on drop
+                let _ = shutdown.await;
+                handle.stop(false).await;
+            });
+
+            server.await.unwrap();
+        });
+    })
+}
+
+/// Bind ephemeral port and run server on it on a dedicated thread.
+///
+/// Port is bound before thread is spawned, so connections are queued until server starts.
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+fn spawn<C, F>(server: F) -> TestServer<C>
+where
+    C: comfund::ContractClient,
+    C::Transport: Default,
+    F: FnOnce(std::net::TcpListener, oneshot::Receiver<()>) + Send + 'static,
+{
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let url = format!("http://{addr}");
+
+    let (shutdown, receiver) = oneshot::channel();
+    let thread = std::thread::spawn(move || server(listener, receiver));
+
+    TestServer {
+        client: C::from_parts(
+            std::borrow::Cow::Owned(url.clone()),
+            C::Transport::default(),
+        ),
+        addr,
+        url,
+        shutdown: Some(shutdown),
+        thread: Some(thread),
+    }
+}
@@ -1,8 +1,7 @@
 use actix_web::web;
 
-use super::model::*;
-
 use super::definition;
+use super::model::*;
 
 pub struct ServiceImpl;
 
@@ -20,6 +19,14 @@ impl definition::actix_web::Service for ServiceImpl {
         web::Json(path_inputs.a + path_inputs.b)
     }
 
+    type MulTwoExtensions = ();
+    async fn mul_two(
+        path_inputs: web::Path<definition::MulTwoPathInputs>,
+        _extensions: Self::MulTwoExtensions,
+    ) -> web::Json<u32> {
+        web::Json(path_inputs.a * path_inputs.b)
+    }
+
     type AddThreeExtensions = ();
     async fn add_three(
         _path_inputs: web::Path<definition::AddThreePathInputs>,
@@ -27,4 +34,65 @@ impl definition::actix_web::Service for ServiceImpl {
     ) -> web::Json<()> {
         web::Json(())
     }
+
+    type GreetExtensions = ();
+    async fn greet(
+        header_inputs: definition::GreetHeaderInputs,
+        _extensions: Self::GreetExtensions,
+    ) -> web::Json<String> {
+        web::Json(format!(
+            "Hello, {} from tenant {}!",
+            header_inputs.user, header_inputs.tenant
+        ))
+    }
+
+    type SessionExtensions = ();
+    async fn session(
+        cookie_inputs: definition::SessionCookieInputs,
+        _extensions: Self::SessionExtensions,
+    ) -> web::Json<String> {
+        web::Json(format!(
+            "{}:{}",
+            cookie_inputs.session, cookie_inputs.locale
+        ))
+    }
+
+    type UploadExtensions = ();
+    async fn upload(
+        _extensions: Self::UploadExtensions,
+        upload: ::comfund::multipart::Multipart<Upload>,
+    ) -> web::Json<String> {
+        web::Json(upload.summary())
+    }
+
+    type TokenExtensions = ();
+    async fn token(
+        _extensions: Self::TokenExtensions,
+        request: web::Form<TokenRequest>,
+    ) -> web::Json<String> {
+        web::Json(format!(
+            "{}:{}:{}",
+            request.grant_type, request.username, request.password
+        ))
+    }
+
+    type DivideExtensions = ();
+    async fn divide(
+        query_inputs: web::Query<definition::DivideQueryInputs>,
+        _extensions: Self::DivideExtensions,
+    ) -> Result<web::Json<u32>, ::comfund::ErrorResponse<DivisionError>> {
+        query_inputs
+            .a
+            .checked_div(query_inputs.b)
+            .map(web::Json)
+            .ok_or(DivisionError::DivisionByZero.into())
+    }
+
+    type CreateNoteExtensions = ();
+    async fn create_note(
+        _extensions: Self::CreateNoteExtensions,
+        text: web::Json<String>,
+    ) -> web::Json<u32> {
+        web::Json(text.len() as u32)
+    }
 }
@@ -1,6 +1,5 @@
-use super::model::*;
-
 use super::definition::*;
+use super::model::*;
 
 pub struct ServiceImpl;
 
@@ -20,6 +19,14 @@ impl axum::Service for ServiceImpl {
         ::axum::Json(path_inputs.a + path_inputs.b)
     }
 
+    type MulTwoExtensions = ();
+    async fn mul_two(
+        path_inputs: ::axum::extract::Path<MulTwoPathInputs>,
+        _extensions: Self::MulTwoExtensions,
+    ) -> ::axum::Json<u32> {
+        ::axum::Json(path_inputs.a * path_inputs.b)
+    }
+
     type AddThreeExtensions = ();
     async fn add_three(
         _path_inputs: ::axum::extract::Path<AddThreePathInputs>,
@@ -27,4 +34,65 @@ impl axum::Service for ServiceImpl {
     ) -> ::axum::Json<()> {
         ::axum::Json(())
     }
+
+    type GreetExtensions = ();
+    async fn greet(
+        header_inputs: GreetHeaderInputs,
+        _extensions: Self::GreetExtensions,
+    ) -> ::axum::Json<String> {
+        ::axum::Json(format!(
+            "Hello, {} from tenant {}!",
+            header_inputs.user, header_inputs.tenant
+        ))
+    }
+
+    type SessionExtensions = ();
+    async fn session(
+        cookie_inputs: SessionCookieInputs,
+        _extensions: Self::SessionExtensions,
+    ) -> ::axum::Json<String> {
+        ::axum::Json(format!(
+            "{}:{}",
+            cookie_inputs.session, cookie_inputs.locale
+        ))
+    }
+
+    type UploadExtensions = ();
+    async fn upload(
+        _extensions: Self::UploadExtensions,
+        upload: ::comfund::multipart::Multipart<Upload>,
+    ) -> ::axum::Json<String> {
+        ::axum::Json(upload.summary())
+    }
+
+    type TokenExtensions = ();
+    async fn token(
+        _extensions: Self::TokenExtensions,
+        request: ::axum::extract::Form<TokenRequest>,
+    ) -> ::axum::Json<String> {
+        ::axum::Json(format!(
+            "{}:{}:{}",
+            request.grant_type, request.username, request.password
+        ))
+    }
+
+    type DivideExtensions = ();
+    async fn divide(
+        query_inputs: ::axum::extract::Query<DivideQueryInputs>,
+        _extensions: Self::DivideExtensions,
+    ) -> Result<::axum::Json<u32>, ::comfund::ErrorResponse<DivisionError>> {
+        query_inputs
+            .a
+            .checked_div(query_inputs.b)
+            .map(::axum::Json)
+            .ok_or(DivisionError::DivisionByZero.into())
+    }
+
+    type CreateNoteExtensions = ();
+    async fn create_note(
+        _extensions: Self::CreateNoteExtensions,
+        text: ::axum::Json<String>,
+    ) -> ::axum::Json<u32> {
+        ::axum::Json(text.len() as u32)
+    }
 }
@@ -1,7 +1,7 @@
+use super::model::*;
+
 /// A simple demonstration of basic features of `comfund`.
-#[comfund::contract(
-    content_type = "application/json"
-)]
+#[comfund::contract(prefix = "/api/v1", content_type = "application/json")]
 pub trait Service {
     /// Hello world! version of axum contract.
     #[endpoint(get, "/", content_type = "text/plain")]
@@ -11,7 +11,41 @@ pub trait Service {
     #[endpoint(get, "/{a}/{b}")]
     fn add_two(#[param(path)] a: u32, #[param(path)] b: u32) -> u32;
 
+    /// Another view of the same url with different method.
+    #[endpoint(patch, "/{a}/{b}")]
+    fn mul_two(#[param(path)] a: u32, #[param(path)] b: u32) -> u32;
+
     /// Slightly more complex example of axum endpoint.
     #[endpoint(get, "/{a}/{b}/{c}")]
     fn add_three(#[param(path)] a: u32, #[param(path)] b: u32, #[param(path)] c: u32);
+
+    /// Endpoint, that receives its params through request headers.
+    #[endpoint(get, "/greet")]
+    fn greet(
+        #[param(header = "X-Tenant-Id")] tenant: u32,
+        #[param(header = "X-User-Name")] user: String,
+    ) -> String;
+
+    /// Endpoint, that receives its params through request cookies.
+    #[endpoint(get, "/session")]
+    fn session(
+        #[param(cookie = "session")] session: String,
+        #[param(cookie = "locale")] locale: String,
+    ) -> String;
+
+    /// Endpoint, that receives `multipart/form-data` body.
+    #[endpoint(post, "/upload")]
+    fn upload(#[param(multipart)] upload: Upload) -> String;
+
+    /// Endpoint, that receives `application/x-www-form-urlencoded` body.
+    #[endpoint(post, "/token")]
+    fn token(#[param(form)] request: TokenRequest) -> String;
+
+    /// Endpoint, that returns typed errors.
+    #[endpoint(get, "/divide")]
+    fn divide(#[param(query)] a: u32, #[param(query)] b: u32) -> Result<u32, DivisionError>;
+
+    /// Endpoint, that responds with `201 Created` status.
+    #[endpoint(post, "/notes", status = 201)]
+    fn create_note(#[param(json)] text: String) -> u32;
 }
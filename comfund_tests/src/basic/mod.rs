@@ -3,46 +3,733 @@ pub mod axum_implelentation;
 pub mod definition;
 pub mod model;
 
-use model::*;
+#[cfg(test)]
+use comfund_test::TestServer;
 
-use crate::{actix_initializators, axum_initializators};
+#[cfg(test)]
+fn launch_axum_server() -> TestServer<definition::ServiceClient> {
+    comfund_test::axum(definition::axum::route_service::<
+        axum_implelentation::ServiceImpl,
+    >(()))
+}
 
-axum_initializators!(
-    "127.0.0.1:10000",
-    AXUM_CLIENT = definition::ServiceClient,
-    launch_axum_server = definition::axum::route_service::<axum_implelentation::ServiceImpl>[()]
-);
+#[cfg(test)]
+fn launch_actix_server() -> TestServer<definition::ServiceClient> {
+    comfund_test::actix(
+        definition::actix_web::configure_service::<actix_implementation::ServiceImpl>,
+    )
+}
 
-actix_initializators!(
-    "127.0.0.1:11000",
-    ACTIX_CLIENT = definition::ServiceClient,
-    launch_actix_server =
-        definition::actix_web::configure_service::<actix_implementation::ServiceImpl>[()]
-);
+/// Servers of contract on both back-ends.
+#[cfg(test)]
+fn servers() -> [TestServer<definition::ServiceClient>; 2] {
+    [launch_axum_server(), launch_actix_server()]
+}
 
 #[tokio::test]
 async fn hello_world() {
-    launch_axum_server().await;
-    launch_actix_server().await;
+    let [axum_server, actix_server] = servers();
+
+    assert_eq!(axum_server.hello_world().await.unwrap(), "Hello world!");
+    assert_eq!(actix_server.hello_world().await.unwrap(), "Hello world!");
+}
 
-    assert_eq!(AXUM_CLIENT.hello_world().await.unwrap(), "Hello world!");
-    assert_eq!(ACTIX_CLIENT.hello_world().await.unwrap(), "Hello world!");
+#[tokio::test]
+async fn prefix() {
+    for server in servers() {
+        let root = server.url();
+        let prefixed = reqwest::get(format!("{root}/api/v1/1/2")).await.unwrap();
+        let unprefixed = reqwest::get(format!("{root}/1/2")).await.unwrap();
+
+        assert_eq!(prefixed.text().await.unwrap(), "3");
+        assert_eq!(unprefixed.status(), reqwest::StatusCode::NOT_FOUND);
+    }
 }
 
 #[tokio::test]
 async fn add_two() {
-    launch_axum_server().await;
-    launch_actix_server().await;
+    let [axum_server, actix_server] = servers();
 
-    assert_eq!(AXUM_CLIENT.add_two(10, 20).await.unwrap(), 30);
-    assert_eq!(ACTIX_CLIENT.add_two(10, 20).await.unwrap(), 30);
+    assert_eq!(axum_server.add_two(10, 20).await.unwrap(), 30);
+    assert_eq!(actix_server.add_two(10, 20).await.unwrap(), 30);
+}
+
+#[tokio::test]
+async fn mul_two() {
+    for server in servers() {
+        assert_eq!(server.mul_two(10, 20).await.unwrap(), 200);
+    }
 }
 
 #[tokio::test]
 async fn add_three() {
-    launch_axum_server().await;
-    launch_actix_server().await;
+    let [axum_server, actix_server] = servers();
+
+    axum_server.add_three(0, 1, 2).await.unwrap();
+    actix_server.add_three(0, 1, 1).await.unwrap();
+}
+
+#[tokio::test]
+async fn greet() {
+    for server in servers() {
+        assert_eq!(
+            server.greet(42, "admin".to_owned()).await.unwrap(),
+            "Hello, admin from tenant 42!"
+        );
+    }
+}
+
+#[tokio::test]
+async fn session() {
+    for server in servers() {
+        let session = "token; with=separators".to_owned();
+
+        assert_eq!(
+            server.session(session, "uk-UA".to_owned()).await.unwrap(),
+            "token; with=separators:uk-UA"
+        );
+    }
+}
+
+#[tokio::test]
+async fn upload() {
+    for server in servers() {
+        let upload = model::Upload {
+            title: "Report".to_owned(),
+            tags: vec!["q1".to_owned(), "draft".to_owned()],
+            description: None,
+            attachment: comfund::multipart::File::new("report.csv", "text/csv", "a,b\n1,2\n"),
+        };
+
+        assert_eq!(
+            server.upload(upload).await.unwrap(),
+            "Report [q1, draft] None: report.csv (text/csv, 8 bytes)"
+        );
+    }
+}
+
+#[tokio::test]
+async fn token() {
+    for server in servers() {
+        let request = model::TokenRequest {
+            grant_type: "password".to_owned(),
+            username: "admin".to_owned(),
+            password: "p@ss w&rd".to_owned(),
+        };
+
+        assert_eq!(
+            server.token(request).await.unwrap(),
+            "password:admin:p@ss w&rd"
+        );
+    }
+}
+
+#[tokio::test]
+async fn divide() {
+    for server in servers() {
+        assert_eq!(server.divide(10, 2).await.unwrap(), Ok(5));
+        assert_eq!(
+            server.divide(10, 0).await.unwrap(),
+            Err(model::DivisionError::DivisionByZero)
+        );
+
+        let root = server.url();
+        let response = reqwest::get(format!("{root}/api/v1/divide?a=1&b=0"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers()[reqwest::header::CONTENT_TYPE],
+            "application/json"
+        );
+    }
+}
+
+#[tokio::test]
+async fn create_note() {
+    for server in servers() {
+        assert_eq!(server.create_note("Buy milk".to_owned()).await.unwrap(), 8);
+
+        let root = server.url();
+        let response = reqwest::Client::new()
+            .post(format!("{root}/api/v1/notes"))
+            .json("Buy milk")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::CREATED);
+        assert_eq!(response.text().await.unwrap(), "8");
+    }
+}
+
+#[tokio::test]
+async fn unexpected_status() {
+    for server in servers() {
+        let root = server.url();
+        let client = definition::ServiceClient::new(&format!("{root}/missing"));
+        let result = client.hello_world().await;
+
+        assert!(matches!(
+            result,
+            Err(comfund::ClientError::Status {
+                status: reqwest::StatusCode::NOT_FOUND,
+                ..
+            })
+        ));
+    }
+}
+
+#[tokio::test]
+async fn configured_clients() {
+    let [axum_server, actix_server] = servers();
+
+    let http_client = reqwest::Client::builder()
+        .user_agent("comfund-tests")
+        .build()
+        .unwrap();
+    let shared = definition::ServiceClient::new(&axum_server.url()).with_client(http_client);
+    let built = definition::ServiceClient::builder(&actix_server.url())
+        .timeout(std::time::Duration::from_secs(5))
+        .user_agent(reqwest::header::HeaderValue::from_static("comfund-tests"))
+        .build()
+        .unwrap();
+
+    assert_eq!(shared.add_two(1, 2).await.unwrap(), 3);
+    assert_eq!(built.add_two(1, 2).await.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn interceptors() {
+    use std::sync::{Arc, Mutex};
+
+    use comfund::{ClientError, EndpointInfo, Interceptor};
+
+    /// Replaces user name and records endpoints with statuses of their responses.
+    #[derive(Clone, Default)]
+    struct Impersonate(Arc<Mutex<Vec<(EndpointInfo, reqwest::StatusCode)>>>);
+
+    impl Interceptor for Impersonate {
+        fn before(
+            &self,
+            _endpoint: &EndpointInfo,
+            request: &mut reqwest::Request,
+        ) -> Result<(), ClientError> {
+            let value = reqwest::header::HeaderValue::from_static("root");
+            request.headers_mut().insert("X-User-Name", value);
+
+            Ok(())
+        }
+
+        fn after(&self, endpoint: &EndpointInfo, response: &reqwest::Response) {
+            let record = (endpoint.clone(), response.status());
+            self.0.lock().unwrap().push(record);
+        }
+    }
+
+    let [axum_server, actix_server] = servers();
+
+    let interceptor = Impersonate::default();
+    let axum_client =
+        definition::ServiceClient::new(&axum_server.url()).with_interceptor(interceptor.clone());
+    let actix_client = definition::ServiceClient::builder(&actix_server.url())
+        .interceptor(interceptor.clone())
+        .build()
+        .unwrap();
+
+    let expected = "Hello, root from tenant 42!";
+
+    assert_eq!(
+        axum_client.greet(42, "admin".to_owned()).await.unwrap(),
+        expected
+    );
+    assert_eq!(
+        actix_client.greet(42, "admin".to_owned()).await.unwrap(),
+        expected
+    );
+
+    let endpoint = EndpointInfo {
+        contract: "Service",
+        endpoint: "greet",
+        method: reqwest::Method::GET,
+        path: "/api/v1/greet",
+    };
+
+    assert_eq!(
+        *interceptor.0.lock().unwrap(),
+        [
+            (endpoint.clone(), reqwest::StatusCode::OK),
+            (endpoint, reqwest::StatusCode::OK)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn request_options() {
+    use std::sync::{Arc, Mutex};
+
+    use comfund::http::header::{HeaderName, HeaderValue};
+    use comfund::{ClientError, EndpointInfo, Interceptor, RequestOptions};
+
+    /// Records idempotency keys of sent requests.
+    #[derive(Clone, Default)]
+    struct Keys(Arc<Mutex<Vec<Option<reqwest::header::HeaderValue>>>>);
+
+    impl Interceptor for Keys {
+        fn before(
+            &self,
+            _endpoint: &EndpointInfo,
+            request: &mut reqwest::Request,
+        ) -> Result<(), ClientError> {
+            let key = request.headers().get("idempotency-key").cloned();
+            self.0.lock().unwrap().push(key);
+
+            Ok(())
+        }
+    }
+
+    let keys = Keys::default();
+    // Root is overridden by options
+    let client =
+        definition::ServiceClient::new(&"http://127.0.0.1:1").with_interceptor(keys.clone());
+
+    for server in servers() {
+        let options = RequestOptions::new()
+            .root(server.url().to_owned())
+            .header(
+                HeaderName::from_static("x-user-name"),
+                HeaderValue::from_static("root"),
+            )
+            .timeout(std::time::Duration::from_secs(5))
+            .idempotency_key(HeaderValue::from_static("greet-1"));
+
+        assert_eq!(
+            client
+                .greet_with(&options, 42, "admin".to_owned())
+                .await
+                .unwrap(),
+            "Hello, root from tenant 42!"
+        );
+    }
+
+    assert!(client.hello_world().await.is_err());
+    assert_eq!(
+        *keys.0.lock().unwrap(),
+        [
+            Some(reqwest::header::HeaderValue::from_static("greet-1")),
+            Some(reqwest::header::HeaderValue::from_static("greet-1")),
+            None
+        ]
+    );
+}
+
+#[tokio::test]
+async fn repeated_headers() {
+    use std::sync::{Arc, Mutex};
+
+    use comfund::http::header::{HeaderName, HeaderValue};
+    use comfund::{ClientError, EndpointInfo, Interceptor, RequestOptions};
+
+    /// Records values of sent `X-Tag` headers.
+    #[derive(Clone, Default)]
+    struct Tags(Arc<Mutex<Vec<String>>>);
+
+    impl Interceptor for Tags {
+        fn before(
+            &self,
+            _endpoint: &EndpointInfo,
+            request: &mut reqwest::Request,
+        ) -> Result<(), ClientError> {
+            let values = request
+                .headers()
+                .get_all("x-tag")
+                .iter()
+                .map(|value| value.to_str().unwrap().to_owned());
+            self.0.lock().unwrap().extend(values);
+
+            Ok(())
+        }
+    }
+
+    let server = launch_axum_server();
+    let tags = Tags::default();
+    let client = definition::ServiceClient::new(&server.url()).with_interceptor(tags.clone());
+
+    let options = RequestOptions::new()
+        .header(
+            HeaderName::from_static("x-tag"),
+            HeaderValue::from_static("first"),
+        )
+        .header(
+            HeaderName::from_static("x-tag"),
+            HeaderValue::from_static("second"),
+        )
+        .header(
+            HeaderName::from_static("x-user-name"),
+            HeaderValue::from_static("root"),
+        );
+
+    // Header, set by endpoint param, is overridden with value of options
+    assert_eq!(
+        client
+            .greet_with(&options, 42, "admin".to_owned())
+            .await
+            .unwrap(),
+        "Hello, root from tenant 42!"
+    );
+    assert_eq!(*tags.0.lock().unwrap(), ["first", "second"]);
+}
+
+#[tokio::test]
+async fn raw_responses() {
+    for server in servers() {
+        let (root, client) = (server.url(), server.client());
+        let created = client.create_note_raw("Buy milk".to_owned()).await.unwrap();
+
+        assert_eq!(created.status(), reqwest::StatusCode::CREATED);
+        assert_eq!(
+            created.headers()[reqwest::header::CONTENT_TYPE],
+            "application/json"
+        );
+        assert_eq!(created.url().to_string(), format!("{root}/api/v1/notes"));
+        assert_eq!(created.into_body(), 8);
+
+        let failed = client.divide_raw(1, 0).await.unwrap();
+
+        assert_eq!(failed.status(), reqwest::StatusCode::BAD_REQUEST);
+        assert_eq!(failed.body(), &Err(model::DivisionError::DivisionByZero));
+    }
+}
+
+#[tokio::test]
+async fn prepared_requests() {
+    for server in servers() {
+        let (root, client) = (server.url(), server.client());
+        let request = client.prepare_divide(10, 2).unwrap().build().unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.url().as_str(),
+            format!("{root}/api/v1/divide?a=10&b=2")
+        );
+
+        // Header, set by endpoint param, is adjusted before sending
+        let mut request = client
+            .prepare_greet(42, "admin".to_owned())
+            .unwrap()
+            .build()
+            .unwrap();
+        request.headers_mut().insert(
+            "X-User-Name",
+            reqwest::header::HeaderValue::from_static("guest"),
+        );
+
+        let response = reqwest::Client::new().execute(request).await.unwrap();
+
+        assert_eq!(
+            response.json::<String>().await.unwrap(),
+            "Hello, guest from tenant 42!"
+        );
+    }
+}
+
+#[tokio::test]
+async fn custom_transport() {
+    use std::sync::{Arc, Mutex};
+
+    use comfund::http;
+    use comfund::{ClientError, ClientTransport, EndpointInfo};
+
+    /// Records sent requests and responds with canned responses, without network.
+    #[derive(Clone, Default)]
+    struct Canned(Arc<Mutex<Vec<http::Request<Vec<u8>>>>>);
+
+    impl ClientTransport for Canned {
+        async fn send(
+            &self,
+            endpoint: &EndpointInfo,
+            request: http::Request<Vec<u8>>,
+        ) -> Result<http::Response<Vec<u8>>, ClientError> {
+            let (status, body) = match endpoint.endpoint {
+                "create_note" => (http::StatusCode::CREATED, "8"),
+                "divide" => (http::StatusCode::BAD_REQUEST, "\"DivisionByZero\""),
+                _ => (http::StatusCode::NOT_FOUND, "missing"),
+            };
+
+            self.0.lock().unwrap().push(request);
+
+            let mut response = http::Response::new(body.as_bytes().to_vec());
+            *response.status_mut() = status;
+
+            Ok(response)
+        }
+    }
+
+    let transport = Canned::default();
+    let client = definition::ServiceClient::with_transport(&"http://canned", transport.clone());
+
+    assert_eq!(client.create_note("Buy milk".to_owned()).await.unwrap(), 8);
+    assert_eq!(
+        client.divide(1, 0).await.unwrap(),
+        Err(model::DivisionError::DivisionByZero)
+    );
+    assert!(matches!(
+        client.hello_world().await,
+        Err(ClientError::Status {
+            status: http::StatusCode::NOT_FOUND,
+            ..
+        })
+    ));
+
+    let requests = transport.0.lock().unwrap();
+    let note = &requests[0];
+
+    assert_eq!(note.method(), http::Method::POST);
+    assert_eq!(note.uri(), "http://canned/api/v1/notes");
+    assert_eq!(
+        note.headers()[http::header::CONTENT_TYPE],
+        "application/json"
+    );
+    assert_eq!(note.body(), b"\"Buy milk\"");
+
+    let divide = &requests[1];
+
+    assert_eq!(divide.uri(), "http://canned/api/v1/divide?a=1&b=0");
+
+    let hello = &requests[2];
+
+    assert_eq!(hello.method(), http::Method::GET);
+    assert_eq!(hello.uri(), "http://canned/api/v1");
+    assert_eq!(requests.len(), 3);
+}
+
+#[tokio::test]
+async fn tower_transport() {
+    use comfund::http;
+
+    // Router is called directly, without binding any port
+    let router = definition::axum::route_service::<axum_implelentation::ServiceImpl>(());
+    let client = definition::ServiceClient::with_transport(
+        &"http://router",
+        comfund::TowerTransport::new(router.clone()),
+    );
+
+    assert_eq!(client.hello_world().await.unwrap(), "Hello world!");
+    assert_eq!(client.add_two(10, 20).await.unwrap(), 30);
+    assert_eq!(
+        client
+            .session("token; with=separators".to_owned(), "uk-UA".to_owned())
+            .await
+            .unwrap(),
+        "token; with=separators:uk-UA"
+    );
+
+    let upload = model::Upload {
+        title: "Report".to_owned(),
+        tags: vec!["q1".to_owned(), "draft".to_owned()],
+        description: None,
+        attachment: comfund::multipart::File::new("report.csv", "text/csv", "a,b\n1,2\n"),
+    };
+
+    assert_eq!(
+        client.upload(upload).await.unwrap(),
+        "Report [q1, draft] None: report.csv (text/csv, 8 bytes)"
+    );
+    assert_eq!(client.create_note("Buy milk".to_owned()).await.unwrap(), 8);
+    assert_eq!(
+        client.divide(10, 0).await.unwrap(),
+        Err(model::DivisionError::DivisionByZero)
+    );
+
+    let missing = definition::ServiceClient::with_transport(
+        &"http://router/missing",
+        comfund::TowerTransport::new(router),
+    );
+
+    assert!(matches!(
+        missing.hello_world().await,
+        Err(comfund::ClientError::Status {
+            status: http::StatusCode::NOT_FOUND,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn loopback_clients() {
+    use comfund::ClientTransport;
+
+    // Requests go through the whole stack of server, without binding any port
+    async fn check<T: ClientTransport>(client: definition::ServiceClient<T>) {
+        assert_eq!(client.hello_world().await.unwrap(), "Hello world!");
+        assert_eq!(client.mul_two(6, 7).await.unwrap(), 42);
+        assert_eq!(
+            client.greet(42, "admin".to_owned()).await.unwrap(),
+            "Hello, admin from tenant 42!"
+        );
+        assert_eq!(
+            client
+                .session("token; with=separators".to_owned(), "uk-UA".to_owned())
+                .await
+                .unwrap(),
+            "token; with=separators:uk-UA"
+        );
+        assert_eq!(
+            client
+                .upload(model::Upload {
+                    title: "Report".to_owned(),
+                    tags: vec!["q1".to_owned()],
+                    description: None,
+                    attachment: comfund::multipart::File::new("report.csv", "text/csv", "a,b\n"),
+                })
+                .await
+                .unwrap(),
+            "Report [q1] None: report.csv (text/csv, 4 bytes)"
+        );
+        assert_eq!(
+            client
+                .token(model::TokenRequest {
+                    grant_type: "password".to_owned(),
+                    username: "admin".to_owned(),
+                    password: "p@ss w&rd".to_owned(),
+                })
+                .await
+                .unwrap(),
+            "password:admin:p@ss w&rd"
+        );
+        assert_eq!(
+            client.divide(10, 0).await.unwrap(),
+            Err(model::DivisionError::DivisionByZero)
+        );
+
+        let created = client.create_note_raw("Buy milk".to_owned()).await.unwrap();
+
+        assert_eq!(created.status(), comfund::http::StatusCode::CREATED);
+        assert_eq!(created.into_body(), 8);
+    }
+
+    check(definition::ServiceClient::loopback_axum(
+        definition::axum::route_service::<axum_implelentation::ServiceImpl>(()),
+    ))
+    .await;
+    check(definition::ServiceClient::loopback_actix(
+        definition::actix_web::configure_service::<actix_implementation::ServiceImpl>,
+    ))
+    .await;
+}
+
+#[test]
+fn codec() {
+    use comfund::http;
+
+    use definition::codec;
+
+    let note = codec::encode_create_note("http://service", "Buy milk".to_owned()).unwrap();
+
+    assert_eq!(note.method(), http::Method::POST);
+    assert_eq!(note.uri(), "http://service/api/v1/notes");
+    assert_eq!(
+        note.headers()[http::header::CONTENT_TYPE],
+        "application/json"
+    );
+    assert_eq!(note.body(), b"\"Buy milk\"");
+
+    let greet = codec::encode_greet("http://service", 7, "Alice".to_owned()).unwrap();
+
+    assert_eq!(greet.uri(), "http://service/api/v1/greet");
+    assert_eq!(greet.headers()["X-Tenant-Id"], "7");
+    assert_eq!(greet.headers()["X-User-Name"], "Alice");
+
+    // Nothing is sent, if arguments can't be encoded
+    assert!(matches!(
+        codec::encode_greet("http://service", 7, "Alice\n".to_owned()),
+        Err(comfund::ClientError::Encode(_))
+    ));
+
+    let divide = codec::encode_divide("http://service", 1, 0).unwrap();
+
+    assert_eq!(divide.method(), http::Method::GET);
+    assert_eq!(divide.uri(), "http://service/api/v1/divide?a=1&b=0");
+    assert!(divide.body().is_empty());
+
+    let response = |status: u16, body: &str| {
+        let mut response = http::Response::new(body.as_bytes().to_vec());
+        *response.status_mut() = http::StatusCode::from_u16(status).unwrap();
+        response
+    };
+
+    assert_eq!(codec::decode_create_note(response(201, "8")).unwrap(), 8);
+    assert!(matches!(
+        codec::decode_create_note(response(200, "8")),
+        Err(comfund::ClientError::Status {
+            status: http::StatusCode::OK,
+            ..
+        })
+    ));
+    assert_eq!(codec::decode_divide(response(200, "5")).unwrap(), Ok(5));
+    assert_eq!(
+        codec::decode_divide(response(400, "\"DivisionByZero\"")).unwrap(),
+        Err(model::DivisionError::DivisionByZero)
+    );
+    assert!(matches!(
+        codec::decode_add_two(response(200, "thirty")),
+        Err(comfund::ClientError::Decode(_))
+    ));
+}
+
+#[test]
+fn blocking_client() {
+    let upload = || model::Upload {
+        title: "Report".to_owned(),
+        tags: vec!["q1".to_owned()],
+        description: None,
+        attachment: comfund::multipart::File::new("report.csv", "text/csv", "a,b\n"),
+    };
+
+    // Servers are launched on their own threads with their own runtimes
+    for server in servers() {
+        let root = server.url();
+        let client = definition::ServiceBlockingClient::new(&root);
+
+        assert_eq!(client.hello_world().unwrap(), "Hello world!");
+        assert_eq!(client.add_two(10, 20).unwrap(), 30);
+        assert_eq!(
+            client
+                .session("token".to_owned(), "uk-UA".to_owned())
+                .unwrap(),
+            "token:uk-UA"
+        );
+        assert_eq!(
+            client.upload(upload()).unwrap(),
+            "Report [q1] None: report.csv (text/csv, 4 bytes)"
+        );
+        assert_eq!(
+            client.divide(1, 0).unwrap(),
+            Err(model::DivisionError::DivisionByZero)
+        );
+
+        let created = client.create_note_raw("Buy milk".to_owned()).unwrap();
+
+        assert_eq!(created.status(), reqwest::StatusCode::CREATED);
+        assert_eq!(created.into_body(), 8);
+
+        let options = comfund::RequestOptions::new().root(format!("{root}/missing"));
+        let result = client.hello_world_with(&options);
+
+        assert!(matches!(
+            result,
+            Err(comfund::ClientError::Status {
+                status: reqwest::StatusCode::NOT_FOUND,
+                ..
+            })
+        ));
+
+        let request = client.prepare_divide(10, 2).unwrap().build().unwrap();
 
-    AXUM_CLIENT.add_three(0, 1, 2).await.unwrap();
-    ACTIX_CLIENT.add_three(0, 1, 1).await.unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            format!("{root}/api/v1/divide?a=10&b=2")
+        );
+    }
 }
@@ -1 +1,41 @@
+use comfund::multipart::{File, MultipartForm};
+use serde::{Deserialize, Serialize};
 
+/// Multipart form with both text and file parts.
+#[derive(MultipartForm)]
+pub struct Upload {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub description: Option<String>,
+    pub attachment: File,
+}
+
+impl Upload {
+    /// Summary of received form, that is sent back by servers.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} [{}] {:?}: {} ({}, {} bytes)",
+            self.title,
+            self.tags.join(", "),
+            self.description,
+            self.attachment.file_name.as_deref().unwrap_or_default(),
+            self.attachment.content_type.as_deref().unwrap_or_default(),
+            self.attachment.data.len()
+        )
+    }
+}
+
+/// OAuth-like token request, sent as `application/x-www-form-urlencoded` body.
+#[derive(Serialize, Deserialize)]
+pub struct TokenRequest {
+    pub grant_type: String,
+    pub username: String,
+    pub password: String,
+}
+
+/// Typed error of `divide` endpoint.
+#[derive(Debug, PartialEq, Serialize, Deserialize, comfund::EndpointError)]
+#[status(400)]
+pub enum DivisionError {
+    DivisionByZero,
+}
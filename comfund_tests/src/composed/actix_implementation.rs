@@ -0,0 +1,51 @@
+use actix_web::web;
+
+use super::definition::{self, billing, common, users};
+
+pub struct AdminImpl;
+
+impl common::actix_web::Common for AdminImpl {
+    type HealthExtensions = ();
+    async fn health(_extensions: Self::HealthExtensions) -> String {
+        "actix is healthy".to_owned()
+    }
+}
+
+impl users::actix_web::Users for AdminImpl {
+    type UserNameExtensions = ();
+    async fn user_name(
+        path_inputs: web::Path<u32>,
+        _extensions: Self::UserNameExtensions,
+    ) -> String {
+        format!("user-{}", path_inputs.into_inner())
+    }
+}
+
+impl billing::actix_web::Billing for AdminImpl {
+    type BalanceExtensions = ();
+    async fn balance(
+        path_inputs: web::Path<u32>,
+        _extensions: Self::BalanceExtensions,
+    ) -> web::Json<i64> {
+        web::Json(path_inputs.into_inner() as i64 * 100)
+    }
+
+    type WithdrawExtensions = ();
+    async fn withdraw(
+        path_inputs: web::Path<billing::WithdrawPathInputs>,
+        _extensions: Self::WithdrawExtensions,
+    ) -> Result<web::Json<i64>, ::comfund::ErrorResponse<::comfund::Problem>> {
+        let inputs = path_inputs.into_inner();
+
+        super::withdraw(inputs.id, inputs.amount)
+            .map(web::Json)
+            .map_err(|balance| super::insufficient_funds(balance).into())
+    }
+}
+
+impl definition::actix_web::Admin for AdminImpl {
+    type StatsExtensions = ();
+    async fn stats(_extensions: Self::StatsExtensions) -> String {
+        "actix".to_owned()
+    }
+}
@@ -0,0 +1,57 @@
+use super::definition::*;
+
+pub struct AdminImpl;
+
+impl common::axum::Common for AdminImpl {
+    type State = ();
+
+    type HealthExtensions = ();
+    async fn health(_extensions: Self::HealthExtensions) -> String {
+        "axum is healthy".to_owned()
+    }
+}
+
+impl users::axum::Users for AdminImpl {
+    type State = ();
+
+    type UserNameExtensions = ();
+    async fn user_name(
+        path_inputs: ::axum::extract::Path<u32>,
+        _extensions: Self::UserNameExtensions,
+    ) -> String {
+        format!("user-{}", path_inputs.0)
+    }
+}
+
+impl billing::axum::Billing for AdminImpl {
+    type State = ();
+
+    type BalanceExtensions = ();
+    async fn balance(
+        path_inputs: ::axum::extract::Path<u32>,
+        _extensions: Self::BalanceExtensions,
+    ) -> ::axum::Json<i64> {
+        ::axum::Json(path_inputs.0 as i64 * 100)
+    }
+
+    type WithdrawExtensions = ();
+    async fn withdraw(
+        path_inputs: ::axum::extract::Path<billing::WithdrawPathInputs>,
+        _extensions: Self::WithdrawExtensions,
+    ) -> Result<::axum::Json<i64>, ::comfund::ErrorResponse<::comfund::Problem>> {
+        let inputs = path_inputs.0;
+
+        super::withdraw(inputs.id, inputs.amount)
+            .map(::axum::Json)
+            .map_err(|balance| super::insufficient_funds(balance).into())
+    }
+}
+
+impl axum::Admin for AdminImpl {
+    type State = ();
+
+    type StatsExtensions = ();
+    async fn stats(_extensions: Self::StatsExtensions) -> String {
+        "axum".to_owned()
+    }
+}
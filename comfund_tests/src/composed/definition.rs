@@ -0,0 +1,42 @@
+pub mod common {
+    /// Contract, inherited by several inherited contracts.
+    #[comfund::contract(prefix = "/common")]
+    pub trait Common {
+        #[endpoint(get, "/health")]
+        fn health() -> String;
+    }
+}
+
+pub mod users {
+    /// Contract, inherited from sibling module.
+    #[comfund::contract(prefix = "/users")]
+    pub trait Users: super::common::Common {
+        #[endpoint(get, "/{id}")]
+        fn user_name(#[param(path)] id: u32) -> String;
+    }
+}
+
+pub mod billing {
+    /// Contract, inherited by absolute path.
+    #[comfund::contract(
+        prefix = "/billing",
+        content_type = "application/json",
+        error = comfund::Problem
+    )]
+    pub trait Billing: crate::composed::definition::common::Common {
+        #[endpoint(get, "/{id}/balance")]
+        fn balance(#[param(path)] id: u32) -> i64;
+
+        /// Endpoint, that returns contract-wide default error.
+        #[endpoint(post, "/{id}/withdraw/{amount}")]
+        fn withdraw(#[param(path)] id: u32, #[param(path)] amount: i64) -> Result<i64>;
+    }
+}
+
+/// Composition of several contracts with endpoints of its own. `Common` contract is inherited
+/// through both `Users` and `Billing`.
+#[comfund::contract(prefix = "/admin")]
+pub trait Admin: users::Users + crate::composed::definition::billing::Billing {
+    #[endpoint(get, "/stats")]
+    fn stats() -> String;
+}
@@ -0,0 +1,247 @@
+pub mod actix_implementation;
+pub mod axum_implementation;
+pub mod definition;
+
+#[cfg(test)]
+use comfund_test::TestServer;
+
+/// Shared logic of `withdraw` endpoint. Returns current balance, if it's insufficient.
+fn withdraw(id: u32, amount: i64) -> Result<i64, i64> {
+    let balance = id as i64 * 100;
+
+    if amount <= balance {
+        Ok(balance - amount)
+    } else {
+        Err(balance)
+    }
+}
+
+fn insufficient_funds(balance: i64) -> comfund::Problem {
+    comfund::Problem::new(409)
+        .with_type("https://example.com/problems/insufficient-funds")
+        .with_title("Insufficient funds")
+        .with_extension("balance", balance)
+}
+
+#[cfg(test)]
+fn launch_axum_server() -> TestServer<definition::AdminClient> {
+    comfund_test::axum(definition::axum::route_admin::<
+        axum_implementation::AdminImpl,
+    >(()))
+}
+
+#[cfg(test)]
+fn launch_actix_server() -> TestServer<definition::AdminClient> {
+    comfund_test::actix(definition::actix_web::configure_admin::<actix_implementation::AdminImpl>)
+}
+
+/// Servers of contract on both back-ends.
+#[cfg(test)]
+fn servers() -> [TestServer<definition::AdminClient>; 2] {
+    [launch_axum_server(), launch_actix_server()]
+}
+
+#[tokio::test]
+async fn own_endpoints() {
+    let [axum_server, actix_server] = servers();
+
+    assert_eq!(axum_server.stats().await.unwrap(), "axum");
+    assert_eq!(actix_server.stats().await.unwrap(), "actix");
+}
+
+#[tokio::test]
+async fn inherited_endpoints() {
+    use definition::billing::BillingApi;
+    use definition::users::UsersApi;
+
+    let [axum_server, actix_server] = servers();
+
+    assert_eq!(axum_server.user_name(7).await.unwrap(), "user-7");
+    assert_eq!(actix_server.user_name(7).await.unwrap(), "user-7");
+
+    assert_eq!(axum_server.balance(3).await.unwrap(), 300);
+    assert_eq!(actix_server.balance(3).await.unwrap(), 300);
+}
+
+/// `Common` endpoints are inherited through two levels and two paths, but mounted once.
+#[tokio::test]
+async fn transitively_inherited_endpoints() {
+    use definition::common::CommonApi;
+
+    let [axum_server, actix_server] = servers();
+
+    assert_eq!(axum_server.health().await.unwrap(), "axum is healthy");
+    assert_eq!(actix_server.health().await.unwrap(), "actix is healthy");
+
+    let users_client = definition::users::UsersClient::new(&axum_server.url());
+    assert_eq!(users_client.health().await.unwrap(), "axum is healthy");
+
+    let url = actix_server.url().to_owned();
+    let health = tokio::task::spawn_blocking(move || {
+        use definition::common::CommonBlockingApi;
+
+        definition::AdminBlockingClient::new(&url).health().unwrap()
+    })
+    .await
+    .unwrap();
+    assert_eq!(health, "actix is healthy");
+
+    let axum_client = definition::AdminClient::loopback_axum(definition::axum::route_admin::<
+        axum_implementation::AdminImpl,
+    >(()));
+    assert_eq!(axum_client.health().await.unwrap(), "axum is healthy");
+}
+
+#[tokio::test]
+async fn loopback_clients() {
+    use definition::billing::BillingApi;
+    use definition::users::UsersApi;
+
+    let axum_client = definition::AdminClient::loopback_axum(definition::axum::route_admin::<
+        axum_implementation::AdminImpl,
+    >(()));
+    let actix_client = definition::AdminClient::loopback_actix(
+        definition::actix_web::configure_admin::<actix_implementation::AdminImpl>,
+    );
+
+    assert_eq!(axum_client.stats().await.unwrap(), "axum");
+    assert_eq!(actix_client.stats().await.unwrap(), "actix");
+
+    assert_eq!(axum_client.user_name(7).await.unwrap(), "user-7");
+    assert_eq!(actix_client.user_name(7).await.unwrap(), "user-7");
+
+    let problem = insufficient_funds(300);
+
+    assert_eq!(
+        axum_client.withdraw(3, 500).await.unwrap(),
+        Err(problem.clone())
+    );
+    assert_eq!(actix_client.withdraw(3, 500).await.unwrap(), Err(problem));
+}
+
+#[tokio::test]
+async fn default_error() {
+    use definition::billing::BillingApi;
+
+    let [axum_server, actix_server] = servers();
+
+    assert_eq!(axum_server.withdraw(3, 100).await.unwrap(), Ok(200));
+    assert_eq!(actix_server.withdraw(3, 100).await.unwrap(), Ok(200));
+
+    let problem = insufficient_funds(300);
+
+    assert_eq!(
+        axum_server.withdraw(3, 500).await.unwrap(),
+        Err(problem.clone())
+    );
+    assert_eq!(actix_server.withdraw(3, 500).await.unwrap(), Err(problem));
+
+    for root in [axum_server.url(), actix_server.url()] {
+        let response = reqwest::Client::new()
+            .post(format!("{root}/billing/3/withdraw/500"))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+        assert_eq!(
+            response.headers()[reqwest::header::CONTENT_TYPE],
+            "application/problem+json"
+        );
+    }
+}
+
+/// Launch gateway-like server, that responds with unexpected responses.
+#[cfg(test)]
+fn launch_gateway() -> TestServer<definition::AdminClient> {
+    let router = ::axum::Router::new()
+        .route(
+            "/users/{id}",
+            ::axum::routing::get(|| async {
+                (
+                    ::axum::http::StatusCode::BAD_GATEWAY,
+                    ::axum::response::Html("<html>Bad gateway</html>"),
+                )
+            }),
+        )
+        .route(
+            "/billing/{id}/balance",
+            ::axum::routing::get(|| async { "not a number" }),
+        )
+        .fallback(|| async {
+            comfund::ErrorResponse(comfund::Problem::new(503).with_title("Maintenance"))
+        });
+
+    comfund_test::axum(router)
+}
+
+#[tokio::test]
+async fn unexpected_responses() {
+    use definition::billing::BillingApi;
+    use definition::users::UsersApi;
+
+    let client = launch_gateway();
+
+    match client.user_name(7).await {
+        Err(err @ comfund::ClientError::Status { .. }) => {
+            assert_eq!(err.status(), Some(reqwest::StatusCode::BAD_GATEWAY));
+            assert_eq!(
+                err.to_string(),
+                "unexpected response status 502 Bad Gateway: <html>Bad gateway</html>"
+            );
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    assert!(matches!(
+        client.balance(7).await,
+        Err(comfund::ClientError::Decode(_))
+    ));
+
+    match client.stats().await {
+        Err(comfund::ClientError::Problem(problem)) => {
+            assert_eq!(
+                problem,
+                comfund::Problem::new(503).with_title("Maintenance").into()
+            )
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn loopback_timeouts() {
+    use std::time::Duration;
+
+    let delay = Duration::from_millis(500);
+    let options = comfund::RequestOptions::new().timeout(Duration::from_millis(50));
+
+    let axum_client = definition::AdminClient::loopback_axum(::axum::Router::new().route(
+        "/admin/stats",
+        ::axum::routing::get(move || async move {
+            tokio::time::sleep(delay).await;
+            "axum"
+        }),
+    ));
+    let actix_client = definition::AdminClient::loopback_actix(move |cfg| {
+        cfg.route(
+            "/admin/stats",
+            ::actix_web::web::get().to(move || async move {
+                ::actix_web::rt::time::sleep(delay).await;
+                "actix"
+            }),
+        );
+    });
+
+    assert!(matches!(
+        axum_client.stats_with(&options).await,
+        Err(comfund::ClientError::Transport(_))
+    ));
+    assert!(matches!(
+        actix_client.stats_with(&options).await,
+        Err(comfund::ClientError::Transport(_))
+    ));
+
+    assert_eq!(axum_client.stats().await.unwrap(), "axum");
+    assert_eq!(actix_client.stats().await.unwrap(), "actix");
+}
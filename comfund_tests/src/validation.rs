@@ -0,0 +1,382 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use rstest::rstest;
+
+/// Expand contract and collect messages of produced compile errors.
+fn expansion_errors(args: TokenStream, input: TokenStream) -> Vec<String> {
+    expansion_error_spans(args, input)
+        .into_iter()
+        .map(|(message, _)| message)
+        .collect()
+}
+
+/// Expand contract and collect messages of produced compile errors along with their spans.
+fn expansion_error_spans(args: TokenStream, input: TokenStream) -> Vec<(String, Span)> {
+    let output = comfund_macro_impl::contract(args, input);
+    let file = syn::parse2::<syn::File>(output).unwrap();
+
+    file.items
+        .into_iter()
+        .filter_map(|item| match item {
+            syn::Item::Macro(item)
+                if item
+                    .mac
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|seg| seg.ident == "compile_error") =>
+            {
+                let span = item.mac.path.segments.first().unwrap().ident.span();
+                let message = item.mac.parse_body::<syn::LitStr>().unwrap().value();
+
+                Some((message, span))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn valid_contract() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/items/{id}")]
+                fn get(#[param(path)] id: u32) -> String;
+
+                #[endpoint(post, "/items/{id}")]
+                fn update(#[param(path)] id: u32, #[param(json)] value: String);
+
+                #[endpoint(get, "/items/{id}/{*rest}")]
+                fn nested(#[param(path)] id: u32, #[param(path)] rest: String);
+            }
+        },
+    );
+
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[test]
+fn repeated_endpoint_ident() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/a")]
+                fn endpoint();
+
+                #[endpoint(get, "/b")]
+                fn endpoint();
+            }
+        },
+    );
+
+    assert_eq!(errors, ["Repeated endpoint ident: endpoint"]);
+}
+
+#[rstest]
+#[case::with("get_with", false)]
+#[case::raw("get_raw", false)]
+#[case::raw_with("get_raw_with", false)]
+#[case::prepare("prepare_get", false)]
+#[case::encode("encode_get", false)]
+#[case::decode("decode_get", false)]
+#[case::new("new", true)]
+#[case::new_const("new_const", true)]
+#[case::with_client("with_client", true)]
+#[case::with_interceptor("with_interceptor", true)]
+#[case::builder("builder", true)]
+#[case::with_transport("with_transport", true)]
+#[case::loopback_axum("loopback_axum", true)]
+#[case::loopback_actix("loopback_actix", true)]
+#[case::root("root", true)]
+#[case::transport("transport", true)]
+#[case::from_parts("from_parts", true)]
+#[case::http_client("http_client", true)]
+fn generated_ident_clash(#[case] clashing: &str, #[case] reserved: bool) {
+    let clashing = syn::Ident::new(clashing, Span::call_site());
+
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/a")]
+                fn get();
+
+                #[endpoint(get, "/b")]
+                fn #clashing();
+            }
+        },
+    );
+
+    let message = if reserved {
+        format!("Endpoint ident `{clashing}` is reserved for function of generated client")
+    } else {
+        format!("Endpoint ident `{clashing}` clashes with function, generated for endpoint `get`")
+    };
+
+    assert_eq!(errors, [message]);
+}
+
+#[test]
+fn invalid_path() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/{a")]
+                fn endpoint();
+            }
+        },
+    );
+
+    assert_eq!(errors, ["invalid path: unclosed capture"]);
+}
+
+#[rstest]
+#[case::unclosed_capture("/a/{b/c", "{b")]
+#[case::invalid_ident("/a/{b-c}", "b-c")]
+#[case::invalid_path_char("/a/b c", " ")]
+#[case::invalid_wildcard("/a/{*b}/c", "{*b}")]
+fn invalid_path_span(#[case] path: &str, #[case] offending: &str) {
+    let input = format!(r#"pub trait Service {{ #[endpoint(get, "{path}")] fn endpoint(); }}"#);
+    let offset = input.find(path).unwrap() + path.find(offending).unwrap();
+
+    let errors = expansion_error_spans(quote!(), input.parse().unwrap());
+
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(errors[0].0.starts_with("invalid path"));
+    assert_eq!(errors[0].1.start().column, offset);
+}
+
+#[rstest]
+#[case::same_path(quote!(#[endpoint(get, "/a/{x}")]), quote!(#[endpoint(get, "/a/{x}")]), quote!(x))]
+#[case::different_captures(quote!(#[endpoint(get, "/a/{x}")]), quote!(#[endpoint(get, "/a/{y}")]), quote!(y))]
+#[case::trailing_slash(quote!(#[endpoint(get, "/a/{x}")]), quote!(#[endpoint(get, "/a/{x}/")]), quote!(x))]
+fn conflicting_endpoints(
+    #[case] first: TokenStream,
+    #[case] second: TokenStream,
+    #[case] second_capture: TokenStream,
+) {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #first
+                fn first(#[param(path)] x: u32);
+
+                #second
+                fn second(#[param(path)] #second_capture: u32);
+            }
+        },
+    );
+
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].starts_with("Conflicting endpoints `first` and `second`"));
+}
+
+#[test]
+fn different_content_types() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(post, "/a")]
+                fn json(#[param(json)] value: String);
+
+                #[endpoint(post, "/a")]
+                fn form(#[param(form)] value: String);
+            }
+        },
+    );
+
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].contains("Dispatching requests by accepted content type is not supported"));
+}
+
+#[test]
+fn differently_named_captures() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/a/{x}")]
+                fn get(#[param(path)] x: u32);
+
+                #[endpoint(delete, "/a/{y}")]
+                fn delete(#[param(path)] y: u32);
+            }
+        },
+    );
+
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].ends_with("but name its captures differently."));
+}
+
+#[test]
+fn unmatched_path_captures() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/{a}/{b}/{*rest}")]
+                fn endpoint(#[param(path)] a: u32, #[param(path)] c: u32);
+            }
+        },
+    );
+
+    assert_eq!(
+        errors,
+        [
+            "Path param `c` has no matching capture in endpoint path.",
+            "Capture `b` has no matching `#[param(path)]` argument.",
+            "Capture `rest` has no matching `#[param(path)]` argument.",
+        ]
+    );
+}
+
+#[test]
+fn repeated_path_capture() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/{a}/{a}")]
+                fn endpoint(#[param(path)] a: u32);
+            }
+        },
+    );
+
+    assert_eq!(errors, ["Capture `a` is repeated in endpoint path."]);
+}
+
+#[test]
+fn flattened_path_params() {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/{a}/{b}/{c}")]
+                fn endpoint(#[param(path)] a: u32, #[param(path, flatten)] rest: Rest);
+            }
+        },
+    );
+
+    assert!(errors.is_empty(), "{errors:?}");
+}
+
+#[rstest]
+#[case::captures("/api/{version}", "Contract prefix can't contain captures.")]
+#[case::relative("api/v1", "Contract prefix should start with `/`.")]
+#[case::invalid_path(
+    "/api v1",
+    "invalid path: static segments of template should be valid url path substrings"
+)]
+fn invalid_prefix(#[case] prefix: &str, #[case] message: &str) {
+    let errors = expansion_errors(
+        quote!(prefix = #prefix),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/")]
+                fn endpoint();
+            }
+        },
+    );
+
+    assert_eq!(errors, [message]);
+}
+
+#[test]
+fn root_prefix() {
+    let output = comfund_macro_impl::contract(
+        quote!(prefix = "/"),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/")]
+                fn endpoint();
+            }
+        },
+    );
+
+    let expected = quote!(::actix_web::web::resource("/")).to_string();
+    assert!(output.to_string().contains(&expected), "{output}");
+}
+
+#[rstest]
+#[case::generic(quote!(Users<u32>))]
+#[case::lifetime(quote!('static))]
+#[case::maybe(quote!(?Sized))]
+fn unsupported_supertrait(#[case] supertrait: TokenStream) {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service: #supertrait {
+                #[endpoint(get, "/")]
+                fn endpoint();
+            }
+        },
+    );
+
+    assert_eq!(
+        errors,
+        ["Only other contracts (specified by path without generic arguments) are supported as supertraits."]
+    );
+}
+
+#[test]
+fn async_endpoints() {
+    let sync_output = comfund_macro_impl::contract(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/{a}")]
+                fn endpoint(#[param(path)] a: u32) -> String;
+            }
+        },
+    );
+    let async_output = comfund_macro_impl::contract(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(get, "/{a}")]
+                async fn endpoint(#[param(path)] a: u32) -> String;
+            }
+        },
+    );
+
+    assert_eq!(sync_output.to_string(), async_output.to_string());
+}
+
+#[rstest]
+#[case::redirect(quote!(301))]
+#[case::client_error(quote!(404))]
+#[case::out_of_range(quote!(1000))]
+fn invalid_status(#[case] status: TokenStream) {
+    let errors = expansion_errors(
+        quote!(),
+        quote! {
+            pub trait Service {
+                #[endpoint(post, "/", status = #status)]
+                fn endpoint();
+            }
+        },
+    );
+
+    assert_eq!(
+        errors,
+        ["Status of successful responses should be a 2xx code."]
+    );
+}
+
+#[rstest]
+#[case::container(quote!(#[status(200)] pub enum Error { A }))]
+#[case::variant(quote!(pub enum Error { A, #[status(302)] B }))]
+fn invalid_error_status(#[case] input: TokenStream) {
+    let error = comfund_macro_impl::endpoint_error(input).to_string();
+
+    assert!(
+        error.contains("Status of endpoint error should be a 4xx or 5xx code."),
+        "{error}"
+    );
+}